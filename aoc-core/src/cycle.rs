@@ -0,0 +1,71 @@
+//! Cycle detection for simulations that are run for too many steps to
+//! simulate one by one, but eventually repeat a state.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// States of a simulation up to the first repeated state. After `start`
+/// steps, the states repeat every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// Number of steps before the first state of the cycle.
+    pub start: usize,
+    /// Number of steps until a state repeats, at least one.
+    pub period: usize,
+    /// All states before the first repeat, starting with the initial state.
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// State after `n` steps from the initial state.
+    pub fn state_after(&self, n: usize) -> &S {
+        if n < self.states.len() {
+            &self.states[n]
+        } else {
+            &self.states[self.start + (n - self.start) % self.period]
+        }
+    }
+}
+
+/// Run a simulation from `initial`, where `step` computes the next state,
+/// until a state repeats. Since `step` must only depend on the state, the
+/// simulation cycles from there on.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(&states[states.len() - 1]);
+        if let Some(&start) = seen.get(&next) {
+            return Cycle {
+                start,
+                period: states.len() - start,
+                states,
+            };
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let cycle = find_cycle(0, |&n| if n == 4 { 2 } else { n + 1 });
+        assert_eq!((cycle.start, cycle.period), (2, 3));
+        assert_eq!(cycle.state_after(0), &0);
+        assert_eq!(cycle.state_after(4), &4);
+        assert_eq!(cycle.state_after(5), &2);
+        assert_eq!(
+            cycle.state_after(1_000_000_000),
+            &(2 + (1_000_000_000 - 2) % 3)
+        );
+
+        let cycle = find_cycle(7, |&n| n);
+        assert_eq!((cycle.start, cycle.period), (0, 1));
+        assert_eq!(cycle.state_after(10), &7);
+    }
+}
@@ -0,0 +1,42 @@
+//! Errors shared by all days, so the runner can tell a malformed input apart
+//! from a puzzle that simply has no answer.
+
+use std::fmt;
+
+use crate::parse::Diagnostic;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not follow the format of the puzzle.
+    Parse(Diagnostic),
+    /// The input is well formed, but breaks an assumption of the puzzle, e.g.
+    /// a grid with rows of different lengths or a reference to a missing node.
+    InvalidInput(String),
+    /// The input is valid, but there is no answer for it.
+    NoSolution,
+}
+
+impl Error {
+    /// Parse error at the start of `at`, which must be a slice of `source`.
+    pub fn parse_at(source: &str, at: &str, message: impl Into<String>) -> Self {
+        Error::Parse(Diagnostic::at(source, at, message))
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Error::InvalidInput(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(diagnostic) => write!(f, "Could not parse input, {}", diagnostic),
+            Error::InvalidInput(message) => write!(f, "Invalid puzzle input: {}", message),
+            Error::NoSolution => write!(f, "No solution found"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
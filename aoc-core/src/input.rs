@@ -0,0 +1,154 @@
+//! Loading of puzzle inputs at runtime. Inputs are personal to each account,
+//! so they are never embedded in the binaries.
+
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::parse;
+
+/// Name of the input file inside each day crate.
+pub const INPUT_FILE: &str = "input.txt";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Source from a command line argument, where `-` means stdin. Without an
+    /// argument the `default` file is used.
+    pub fn from_arg(arg: Option<&str>, default: impl Into<PathBuf>) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::File(default.into()),
+        }
+    }
+
+    /// Read the whole input, with normalized line endings (see
+    /// [`parse::normalize`]). Missing or empty inputs are reported as errors.
+    pub fn read(&self) -> Result<String, InputError> {
+        let input = match self {
+            Source::File(path) => std::fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        }
+        .map_err(|err| {
+            let kind = match err.kind() {
+                io::ErrorKind::NotFound => ErrorKind::Missing,
+                _ => ErrorKind::Io(err),
+            };
+            InputError {
+                source: self.clone(),
+                kind,
+            }
+        })?;
+
+        let input = parse::normalize(&input);
+        if input.trim().is_empty() {
+            return Err(InputError {
+                source: self.clone(),
+                kind: ErrorKind::Empty,
+            });
+        }
+        Ok(input.into_owned())
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Missing,
+    Empty,
+    Io(io::Error),
+}
+
+/// Error when a puzzle input could not be loaded.
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Missing => write!(
+                f,
+                "No puzzle input found at {}. Save your input there, or pass a path \
+                 (or `-` for stdin) as argument.",
+                self.source
+            ),
+            ErrorKind::Empty => write!(f, "Puzzle input at {} is empty", self.source),
+            ErrorKind::Io(err) => write!(f, "Could not read {}: {}", self.source, err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Default input of a day crate, i.e. `input.txt` in the crate directory.
+pub fn default_path(crate_dir: impl AsRef<Path>) -> PathBuf {
+    crate_dir.as_ref().join(INPUT_FILE)
+}
+
+/// Load the input for a day binary. The first command line argument is used
+/// as source if present, otherwise `input.txt` in `crate_dir`.
+pub fn from_args(crate_dir: impl AsRef<Path>) -> Result<String, InputError> {
+    let arg = std::env::args().nth(1);
+    Source::from_arg(arg.as_deref(), default_path(crate_dir)).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg(Some("-"), "a.txt"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("b.txt"), "a.txt"),
+            Source::File("b.txt".into())
+        );
+        assert_eq!(
+            Source::from_arg(None, "a.txt"),
+            Source::File("a.txt".into())
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("input.txt"), "1 2 3\n").unwrap();
+        std::fs::write(dir.join("windows.txt"), "\u{feff}1 2\r\n3\r\n").unwrap();
+        std::fs::write(dir.join("empty.txt"), "\n").unwrap();
+
+        let read = |name: &str| Source::File(dir.join(name)).read();
+        assert_eq!(read("input.txt").unwrap(), "1 2 3\n");
+        assert_eq!(read("windows.txt").unwrap(), "1 2\n3\n");
+        assert!(matches!(
+            read("empty.txt").unwrap_err().kind,
+            ErrorKind::Empty
+        ));
+        assert!(matches!(
+            read("missing.txt").unwrap_err().kind,
+            ErrorKind::Missing
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
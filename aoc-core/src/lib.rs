@@ -0,0 +1,9 @@
+pub mod cycle;
+pub mod error;
+pub mod input;
+pub mod parse;
+pub mod solution;
+
+pub use cycle::{find_cycle, Cycle};
+pub use error::{Error, Result};
+pub use solution::{run, DynSolution, Part, Solution};
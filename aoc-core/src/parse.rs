@@ -0,0 +1,442 @@
+//! Error type shared by the nom parsers of all days. Unlike the default nom
+//! error it remembers what was expected, so that a failure can be shown as a
+//! [`Diagnostic`] pointing at the offending line and column of the input.
+//!
+//! The combinators here accept both Unix and Windows line endings, and
+//! [`normalize`] turns any input into Unix line endings up front.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::InputLength;
+
+use crate::Error;
+
+/// Result of a parser using [`ParseError`] by default.
+pub type IResult<I, O, E = ParseError<I>> = nom::IResult<I, O, E>;
+
+/// Something the parser expected, but did not find.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Char(char),
+    Tag(&'static str),
+    Kind(ErrorKind),
+    Message(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Char(c) => write!(f, "{:?}", c),
+            Expected::Tag(tag) => write!(f, "{:?}", tag),
+            Expected::Kind(kind) => f.write_str(describe(kind)),
+            Expected::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+/// Human readable description of what a nom parser failing with `kind`
+/// expected.
+fn describe(kind: &ErrorKind) -> &str {
+    match kind {
+        ErrorKind::Digit => "a number",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line ending",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::Char | ErrorKind::OneOf => "a valid character",
+        ErrorKind::TakeWhile1 | ErrorKind::TakeTill1 => "at least one valid character",
+        kind => kind.description(),
+    }
+}
+
+/// Parse error remembering where the input went wrong and what was expected
+/// there. When several alternatives fail at the same position, all of them are
+/// kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<I> {
+    /// Remaining input where the error occurred.
+    pub input: I,
+    pub expected: Vec<Expected>,
+    /// Innermost context given with [`nom::error::context`].
+    pub context: Option<&'static str>,
+}
+
+impl<I> ParseError<I> {
+    pub fn new(input: I, expected: Expected) -> Self {
+        ParseError {
+            input,
+            expected: vec![expected],
+            context: None,
+        }
+    }
+}
+
+impl<I: InputLength> nom::error::ParseError<I> for ParseError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        ParseError::new(input, Expected::Kind(kind))
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        ParseError::new(input, Expected::Char(c))
+    }
+
+    fn or(mut self, mut other: Self) -> Self {
+        // Keep the error that got furthest into the input
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for expected in other.expected.drain(..) {
+                    if !self.expected.contains(&expected) {
+                        self.expected.push(expected);
+                    }
+                }
+                self.context = self.context.or(other.context);
+                self
+            }
+        }
+    }
+}
+
+impl<I> ContextError<I> for ParseError<I> {
+    fn add_context(_input: I, context: &'static str, mut other: Self) -> Self {
+        other.context.get_or_insert(context);
+        other
+    }
+}
+
+impl<I, E: fmt::Display> FromExternalError<I, E> for ParseError<I> {
+    fn from_external_error(input: I, _kind: ErrorKind, err: E) -> Self {
+        ParseError::new(input, Expected::Message(err.to_string()))
+    }
+}
+
+/// Location of a parse error in the input, with the source line for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Diagnostic pointing at the start of `at`, which must be a slice of
+    /// `source`. Slices outside of `source` point at its start.
+    pub fn at(source: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|&offset| offset <= source.len())
+            .unwrap_or(0);
+
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find(['\r', '\n'])
+            .map_or(source.len(), |i| offset + i);
+
+        Diagnostic {
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            source_line: source[line_start..line_end].to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Diagnostic for a parser that failed on `source`.
+    pub fn from_error(source: &str, err: &ParseError<&str>) -> Self {
+        let mut message = match err.expected.as_slice() {
+            [] => "unexpected input".to_string(),
+            [expected] => format!("expected {}", expected),
+            [rest @ .., last] => format!(
+                "expected one of {} or {}",
+                rest.iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                last
+            ),
+        };
+        if let Some(context) = err.context {
+            message = format!("{} in {}", message, context);
+        }
+        Diagnostic::at(source, err.input, message)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.source_line)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+/// Run `parser` on the whole `source`. Input left after the parser, apart
+/// from trailing whitespace, is reported as an error.
+pub fn all<'a, O>(
+    source: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, Error> {
+    match parser(source) {
+        Ok((rest, output)) => {
+            let rest = rest.trim_start();
+            if rest.is_empty() {
+                Ok(output)
+            } else {
+                Err(Error::Parse(Diagnostic::at(
+                    source,
+                    rest,
+                    "expected end of input",
+                )))
+            }
+        }
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(Error::Parse(Diagnostic::from_error(source, &err)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(Error::Parse(Diagnostic::at(
+            source,
+            &source[source.len()..],
+            "unexpected end of input",
+        ))),
+    }
+}
+
+/// Same as [`nom::bytes::complete::tag`], but remembers the expected tag.
+pub fn tag<'a>(tag: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| match input.strip_prefix(tag) {
+        Some(rest) => Ok((rest, &input[..tag.len()])),
+        None => Err(nom::Err::Error(ParseError::new(input, Expected::Tag(tag)))),
+    }
+}
+
+/// Parse one item per line, separated by line endings. Unlike
+/// `separated_list1(line_ending, parser)` a line that does not parse is an
+/// error, instead of silently ending the list. The list ends at the end of the
+/// input or at an empty line, which is left in the input.
+pub fn lines<'a, O>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    move |input: &'a str| {
+        let (mut input, first) = parser(input)?;
+        let mut items = vec![first];
+        loop {
+            let Some(next) = strip_line_ending(input) else {
+                return Ok((input, items));
+            };
+            if next.is_empty() || strip_line_ending(next).is_some() {
+                return Ok((input, items));
+            }
+            let (rest, item) = parser(next).map_err(|err| match err {
+                nom::Err::Error(err) => nom::Err::Failure(err),
+                err => err,
+            })?;
+            items.push(item);
+            input = rest;
+        }
+    }
+}
+
+/// Parse blocks of lines separated by one or more empty lines, e.g. the
+/// patterns of a puzzle that are each a few lines long. As with [`lines`], a
+/// block that does not parse is an error.
+pub fn blocks<'a, O>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    move |input: &'a str| {
+        let (mut input, first) = parser(input)?;
+        let mut items = vec![first];
+        loop {
+            // The line ending of the last line, and at least one empty line
+            let Some(mut next) = strip_line_ending(input).and_then(strip_line_ending) else {
+                return Ok((input, items));
+            };
+            while let Some(rest) = strip_line_ending(next) {
+                next = rest;
+            }
+            if next.is_empty() {
+                return Ok((input, items));
+            }
+            let (rest, item) = parser(next).map_err(|err| match err {
+                nom::Err::Error(err) => nom::Err::Failure(err),
+                err => err,
+            })?;
+            items.push(item);
+            input = rest;
+        }
+    }
+}
+
+/// The rest of the current line, without its line ending. The line must not
+/// be empty.
+pub fn line(input: &str) -> IResult<&str, &str> {
+    let end = input.find(['\r', '\n']).unwrap_or(input.len());
+    if end == 0 {
+        return Err(nom::Err::Error(ParseError::new(
+            input,
+            Expected::Kind(ErrorKind::TakeTill1),
+        )));
+    }
+    Ok((&input[end..], &input[..end]))
+}
+
+/// Input with a byte order mark removed and all line endings turned into
+/// `\n`, so that code working on the raw text does not have to care whether
+/// the file was saved with Windows or Unix line endings. The input is only
+/// copied if it changes.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+fn strip_line_ending(input: &str) -> Option<&str> {
+    input
+        .strip_prefix("\r\n")
+        .or_else(|| input.strip_prefix('\n'))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::branch::alt;
+    use nom::character::complete::{self, char};
+    use nom::combinator::value;
+
+    use super::*;
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        lines(complete::u32)(input)
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(numbers("1\n2\r\n3\n"), Ok(("\n", vec![1, 2, 3])));
+        assert_eq!(numbers("1\n2\n\n3"), Ok(("\n\n3", vec![1, 2])));
+        assert!(matches!(numbers("1\nx"), Err(nom::Err::Failure(_))));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let parser = |input| blocks(numbers)(input);
+        assert_eq!(
+            parser("1\n2\n\n3\r\n\r\n\r\n4\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3], vec![4]]))
+        );
+        assert_eq!(parser("1\n\n"), Ok(("\n\n", vec![vec![1]])));
+        assert!(matches!(parser("1\n\nx"), Err(nom::Err::Failure(_))));
+    }
+
+    #[test]
+    fn test_line() {
+        assert_eq!(line("ab\r\ncd"), Ok(("\r\ncd", "ab")));
+        assert_eq!(line("ab"), Ok(("", "ab")));
+        assert!(line("\nab").is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+        assert_eq!(normalize("\u{feff}a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("\u{feff}a"), "a");
+    }
+
+    /// Sums of blocks of numbers separated by empty lines.
+    struct Sums;
+
+    impl crate::Solution for Sums {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sums";
+        type Input<'a> = Vec<Vec<u32>>;
+
+        fn parse(input: &str) -> crate::Result<Self::Input<'_>> {
+            all(input, blocks(numbers))
+        }
+
+        fn part1(input: &Self::Input<'_>) -> crate::Result<String> {
+            Ok(input.len().to_string())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> crate::Result<String> {
+            let sums = input.iter().map(|block| block.iter().sum::<u32>());
+            Ok(sums.max().unwrap_or(0).to_string())
+        }
+    }
+
+    /// `Solution::solve_part1` and `solve_part2` normalize the input before
+    /// parsing it.
+    #[test]
+    fn test_solve_normalizes() {
+        use crate::Solution;
+
+        let unix = "1\n2\n\n3\n4";
+        let windows = unix.replace('\n', "\r\n");
+        let variants = [
+            unix.to_string(),
+            format!("{}\n", unix),
+            format!("{}\r\n", windows),
+            format!("\u{feff}{}", unix),
+            format!("\u{feff}{}\r\n", windows),
+        ];
+        for variant in &variants {
+            assert_eq!(
+                Sums::solve_part1(variant),
+                Ok("2".to_string()),
+                "{:?}",
+                variant
+            );
+            assert_eq!(
+                Sums::solve_part2(variant),
+                Ok("7".to_string()),
+                "{:?}",
+                variant
+            );
+        }
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let source = "1\n2\n3x\n";
+        let err = all(source, numbers).unwrap_err();
+        let Error::Parse(diagnostic) = err else {
+            panic!("Expected parse error");
+        };
+        assert_eq!((diagnostic.line, diagnostic.column), (3, 2));
+        assert_eq!(
+            diagnostic.to_string(),
+            "line 3, column 2: expected end of input\n  |\n3 | 3x\n  |  ^"
+        );
+    }
+
+    #[test]
+    fn test_expected() {
+        let source = "ab\nc";
+        let parser = alt((tag("ab\nd"), tag("e"), value("f", char('f'))));
+        let err = all(source, parser).unwrap_err();
+        let Error::Parse(diagnostic) = err else {
+            panic!("Expected parse error");
+        };
+        assert_eq!(
+            diagnostic.message,
+            "expected one of \"ab\\nd\", \"e\" or 'f'"
+        );
+        assert_eq!((diagnostic.line, diagnostic.column), (1, 1));
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::{input, parse, Result};
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Part from its number, i.e. 1 or 2.
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Solution for a single day of the calendar. The puzzle input is parsed once
+/// into a typed model, which is then shared by both parts.
+pub trait Solution {
+    /// Day of the calendar (1-25).
+    const DAY: u8;
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+
+    /// Parsed puzzle input, may borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<String>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<String>;
+
+    /// Normalize and parse the raw input (see [`parse::normalize`]), and
+    /// solve part 1.
+    fn solve_part1(input: &str) -> Result<String> {
+        Self::part1(&Self::parse(&parse::normalize(input))?)
+    }
+
+    /// Normalize and parse the raw input (see [`parse::normalize`]), and
+    /// solve part 2.
+    fn solve_part2(input: &str) -> Result<String> {
+        Self::part2(&Self::parse(&parse::normalize(input))?)
+    }
+}
+
+/// Object safe version of [`Solution`], implemented for all solutions. Makes
+/// it possible to keep different days in one collection and drive them
+/// generically.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// Parse the raw input and solve the given part.
+    fn solve(&self, part: Part, input: &str) -> Result<String>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn solve(&self, part: Part, input: &str) -> Result<String> {
+        match part {
+            Part::One => S::solve_part1(input),
+            Part::Two => S::solve_part2(input),
+        }
+    }
+}
+
+/// Entry point shared by the binaries of all days. Loads the input (see
+/// [`input::from_args`]) and prints the results of both parts.
+pub fn run<S: Solution>(crate_dir: impl AsRef<Path>) -> ExitCode {
+    let input = match input::from_args(crate_dir) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Output results for both parts
+    println!("Day {:02}: {}", S::DAY, S::TITLE);
+    let mut code = ExitCode::SUCCESS;
+    for (part, result) in [
+        (Part::One, S::part1(&parsed)),
+        (Part::Two, S::part2(&parsed)),
+    ] {
+        match result {
+            Ok(answer) => println!("Part {}:\n  {}", part, answer),
+            Err(err) => {
+                println!("Part {}:\n  {}", part, err);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input.split(',').collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<String> {
+            Ok(input.len().to_string())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<String> {
+            input
+                .iter()
+                .map(|n| n.parse::<u32>().map_err(|_| Error::invalid(*n)))
+                .sum::<Result<u32>>()
+                .map(|n| n.to_string())
+        }
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Sum;
+        assert_eq!(solution.day(), 1);
+        assert_eq!(solution.title(), "Sum");
+        assert_eq!(solution.solve(Part::One, "1,2,3").unwrap(), "3");
+        assert_eq!(solution.solve(Part::Two, "1,2,3").unwrap(), "6");
+        assert_eq!(
+            solution.solve(Part::Two, "1,a").unwrap_err(),
+            Error::invalid("a")
+        );
+    }
+
+    #[test]
+    fn test_part() {
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::One.number(), 1);
+    }
+}
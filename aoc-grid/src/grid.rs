@@ -0,0 +1,309 @@
+//! Rectangular grid of cells, stored row by row.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_core::{Error, Result};
+
+use crate::Direction;
+
+/// Position of a cell as (x, y), i.e. (column, row), starting at the top left.
+pub type Pos = (usize, usize);
+
+/// Offsets of the eight cells around a cell, including the diagonals.
+const AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parse a map of characters, one row per line. Every character is
+    /// converted by `cell`, and an error from it is reported at the character.
+    /// All rows must have the same, non-zero, length.
+    pub fn parse<E: fmt::Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> std::result::Result<T, E>,
+    ) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            let mut len = 0;
+            for (i, c) in line.char_indices() {
+                let value =
+                    cell(c).map_err(|err| Error::parse_at(input, &line[i..], err.to_string()))?;
+                cells.push(value);
+                len += 1;
+            }
+            match width {
+                None if len == 0 => return Err(Error::invalid("empty grid")),
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(Error::parse_at(
+                        input,
+                        &line[line.len()..],
+                        format!("expected a row of {} cells, found {}", width, len),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let width = width.ok_or_else(|| Error::invalid("empty grid"))?;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Grid from its rows, which must all have the same, non-zero, length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(Error::invalid("empty grid"));
+        }
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::invalid(format!(
+                "row {} has {} cells instead of {}",
+                y + 1,
+                rows[y].len(),
+                width
+            )));
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Position `offset` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Position one step from `pos` in `direction`, if it is inside the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        self.offset(pos, direction.offset())
+    }
+
+    /// Neighbours of `pos` up, down, left and right, that are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Neighbours of `pos` including the diagonals, that are inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        AROUND
+            .into_iter()
+            .filter_map(move |offset| self.offset(pos, offset))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width)
+    }
+
+    /// Cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "Column {} is outside of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator>
+           + ExactSizeIterator {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Grid with the same cells converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid mirrored along its diagonal, so that its rows are the columns of
+    /// this grid.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parse a map of characters as is.
+    pub fn parse_chars(input: &str) -> Result<Self> {
+        Grid::parse(input, Ok::<_, &str>)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(
+            self.contains((x, y)),
+            "Position ({}, {}) is outside of the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(
+            self.contains((x, y)),
+            "Position ({}, {}) is outside of the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\r\n.#.\r\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(MAP, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected '#' or '.'"),
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(1, 1)]);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.position(|&cell| cell), Some((0, 0)));
+
+        let Err(Error::Parse(diagnostic)) = Grid::parse_chars("ab\nabc\n") else {
+            panic!("Expected parse error");
+        };
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 4));
+        assert!(Grid::parse_chars("").is_err());
+        assert!(Grid::parse("a.", |c| if c == '.' { Ok(c) } else { Err("expected '.'") }).is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        assert_eq!(grid.step((2, 1), Direction::Right), None);
+        assert_eq!(grid.step((2, 1), Direction::Up), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse_chars("abc\ndef").unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.rev().collect::<String>())
+                .collect::<Vec<_>>(),
+            ["da", "eb", "fc"]
+        );
+
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.row(2), ['c', 'f']);
+        assert_eq!(transposed.transpose(), grid);
+    }
+}
@@ -0,0 +1,8 @@
+//! Two dimensional grids, as used by the many puzzles whose input is a map of
+//! characters.
+
+pub mod direction;
+pub mod grid;
+
+pub use direction::Direction;
+pub use grid::{Grid, Pos};
@@ -0,0 +1,156 @@
+//! A single interval of integers.
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+use crate::Point;
+
+/// Half-open interval `start..end` of integers. It is empty if `start` is not
+/// below `end`. Like `Range`, it can never hold the largest value of `T`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Point> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// Interval `first..=last`, or `None` if `last` is the largest value of
+    /// the type, so that the end is not representable.
+    pub fn inclusive(first: T, last: T) -> Option<Self> {
+        let end = T::from_i128(last.to_i128() + 1)?;
+        Some(Interval::new(first, end))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of values in the interval.
+    pub fn len(&self) -> u128 {
+        if self.is_empty() {
+            0
+        } else {
+            (self.end.to_i128() - self.start.to_i128()) as u128
+        }
+    }
+
+    /// Last value in the interval, if it is not empty.
+    pub fn last(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            T::from_i128(self.end.to_i128() - 1)
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Values in both intervals, or `None` if there are none.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let interval = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!interval.is_empty()).then_some(interval)
+    }
+
+    /// Split into the values below `at`, and the values from `at` on. Either
+    /// part is `None` if it would be empty.
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let below = Interval::new(self.start, at.min(self.end));
+        let above = Interval::new(at.max(self.start), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// Interval with every value moved by `offset`, or `None` if a value
+    /// would not fit in the type.
+    pub fn shift(&self, offset: i128) -> Option<Self> {
+        if self.is_empty() {
+            return Some(*self);
+        }
+        Some(Interval::new(
+            T::from_i128(self.start.to_i128() + offset)?,
+            T::from_i128(self.end.to_i128() + offset)?,
+        ))
+    }
+
+    pub fn range(&self) -> Range<T> {
+        self.start..self.end
+    }
+}
+
+impl<T: Point> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl<T: Point> TryFrom<RangeInclusive<T>> for Interval<T> {
+    type Error = RangeInclusive<T>;
+
+    /// Fails if the end of the range is the largest value of the type.
+    fn try_from(range: RangeInclusive<T>) -> Result<Self, Self::Error> {
+        Interval::inclusive(*range.start(), *range.end()).ok_or(range)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(2u8, 5);
+        assert_eq!(interval.len(), 3);
+        assert_eq!(interval.last(), Some(4));
+        assert!(interval.contains(2) && !interval.contains(5));
+        assert!(Interval::new(5u8, 2).is_empty());
+        assert_eq!(Interval::new(5u8, 2).len(), 0);
+
+        assert_eq!(
+            Interval::inclusive(1u32, 4000),
+            Some(Interval::new(1, 4001))
+        );
+        assert_eq!(Interval::inclusive(0u8, 255), None);
+        assert_eq!(Interval::try_from(0i8..=9), Ok(Interval::new(0, 10)));
+        assert_eq!(Interval::new(0u64, u64::MAX).len(), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_split_and_intersect() {
+        let interval = Interval::from(10i32..20);
+        assert_eq!(
+            interval.split_at(15),
+            (Some(Interval::new(10, 15)), Some(Interval::new(15, 20)))
+        );
+        assert_eq!(interval.split_at(10), (None, Some(interval)));
+        assert_eq!(interval.split_at(25), (Some(interval), None));
+
+        assert_eq!(
+            interval.intersect(&Interval::new(-5, 12)),
+            Some(Interval::new(10, 12))
+        );
+        assert_eq!(interval.intersect(&Interval::new(20, 30)), None);
+    }
+
+    #[test]
+    fn test_shift() {
+        let interval = Interval::new(10u8, 20);
+        assert_eq!(interval.shift(-10), Some(Interval::new(0, 10)));
+        assert_eq!(interval.shift(-11), None);
+        assert_eq!(interval.shift(236), None);
+        assert_eq!(interval.shift(235), Some(Interval::new(245, 255)));
+    }
+}
@@ -0,0 +1,13 @@
+//! Intervals of integers, sets of disjoint intervals and maps that offset
+//! whole intervals at once, so that puzzles over huge ranges never have to
+//! visit the values one by one.
+
+pub mod interval;
+pub mod map;
+pub mod point;
+pub mod set;
+
+pub use interval::Interval;
+pub use map::{OffsetMap, OutOfRange};
+pub use point::Point;
+pub use set::RangeSet;
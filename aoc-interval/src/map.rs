@@ -0,0 +1,278 @@
+//! Maps that move the values of some intervals by a fixed offset.
+
+use std::fmt;
+
+use crate::{Interval, Point, RangeSet};
+
+/// An interval was moved outside the values of its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange;
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "interval moved out of range")
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+impl From<OutOfRange> for aoc_core::Error {
+    fn from(err: OutOfRange) -> Self {
+        aoc_core::Error::invalid(err.to_string())
+    }
+}
+
+const IN_RANGE: &str = "Pieces are checked to stay in range when added";
+
+/// Piecewise map of integers. Each piece moves the values of an interval by
+/// an offset. If pieces overlap, the piece added first is used, and values
+/// outside of all pieces are left unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetMap<T> {
+    pieces: Vec<(Interval<T>, i128)>,
+}
+
+impl<T: Point> OffsetMap<T> {
+    pub fn new() -> Self {
+        OffsetMap { pieces: Vec::new() }
+    }
+
+    /// Add a piece moving the values of `source` by `offset`. Fails if a value
+    /// would be moved outside of the type.
+    pub fn push(&mut self, source: Interval<T>, offset: i128) -> Result<(), OutOfRange> {
+        source.shift(offset).ok_or(OutOfRange)?;
+        self.pieces.push((source, offset));
+        Ok(())
+    }
+
+    /// The pieces in the order they were added.
+    pub fn pieces(&self) -> &[(Interval<T>, i128)] {
+        &self.pieces
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        match self
+            .pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+        {
+            Some(&(_, offset)) => T::from_i128(value.to_i128() + offset).expect(IN_RANGE),
+            None => value,
+        }
+    }
+
+    /// Images of all values of `interval`.
+    pub fn apply_interval(&self, interval: Interval<T>) -> RangeSet<T> {
+        self.apply_set(&RangeSet::from(interval))
+    }
+
+    /// Images of all values of `set`.
+    pub fn apply_set(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let mut image = RangeSet::new();
+        let mut rest = set.clone();
+        for &(source, offset) in &self.pieces {
+            let source = RangeSet::from(source);
+            for interval in rest.intersection(&source).intervals() {
+                image.insert(interval.shift(offset).expect(IN_RANGE));
+            }
+            // Values already moved are not moved again by later pieces
+            rest = rest.difference(&source);
+        }
+        image.union(&rest)
+    }
+
+    /// All values that are mapped into `set`.
+    pub fn preimage(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        let pieces = self.disjoint_pieces();
+        // Values outside of all pieces are their own image
+        let sources: RangeSet<T> = pieces.iter().map(|&(source, _)| source).collect();
+        let mut preimage = set.difference(&sources);
+        for (source, offset) in pieces {
+            let image = RangeSet::from(source.shift(offset).expect(IN_RANGE));
+            for interval in set.intersection(&image).intervals() {
+                preimage.insert(interval.shift(-offset).expect(IN_RANGE));
+            }
+        }
+        preimage
+    }
+
+    /// Map applying this map first and then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut pieces = Vec::new();
+        let mut sources = RangeSet::new();
+        for (source, offset) in self.disjoint_pieces() {
+            sources.insert(source);
+            let image = source.shift(offset).expect(IN_RANGE);
+            for (part, next_offset) in next.split(image) {
+                pieces.push((part.shift(-offset).expect(IN_RANGE), offset + next_offset));
+            }
+        }
+        // Values left unchanged by this map are only moved by `next`
+        for (source, offset) in next.disjoint_pieces() {
+            let rest = RangeSet::from(source).difference(&sources);
+            pieces.extend(rest.intervals().iter().map(|&part| (part, offset)));
+        }
+
+        pieces.retain(|&(_, offset)| offset != 0);
+        pieces.sort_by_key(|(source, _)| source.start);
+        OffsetMap { pieces }
+    }
+
+    /// Map undoing this map, or `None` if two values are mapped to the same
+    /// value, or some value is not the image of any value.
+    pub fn inverse(&self) -> Option<Self> {
+        let pieces = self.disjoint_pieces();
+        let sources: RangeSet<T> = pieces.iter().map(|&(source, _)| source).collect();
+        let mut images = RangeSet::new();
+        let mut inverse = Vec::new();
+        for (source, offset) in pieces {
+            let image = source.shift(offset).expect(IN_RANGE);
+            if !images.intersection(&RangeSet::from(image)).is_empty() {
+                return None;
+            }
+            images.insert(image);
+            inverse.push((image, -offset));
+        }
+        // Values outside of the sources map to themselves, so the images must
+        // cover exactly the sources for the map to be a bijection
+        if images != sources {
+            return None;
+        }
+        inverse.sort_by_key(|(source, _)| source.start);
+        Some(OffsetMap { pieces: inverse })
+    }
+
+    /// Pieces that do not overlap, sorted by their start, mapping all values
+    /// the same way as the pieces in the order they were added.
+    fn disjoint_pieces(&self) -> Vec<(Interval<T>, i128)> {
+        let mut pieces = Vec::new();
+        let mut covered = RangeSet::new();
+        for &(source, offset) in &self.pieces {
+            let rest = RangeSet::from(source).difference(&covered);
+            pieces.extend(rest.intervals().iter().map(|&part| (part, offset)));
+            covered.insert(source);
+        }
+        pieces.sort_by_key(|(source, _)| source.start);
+        pieces
+    }
+
+    /// Split `interval` into the parts that are moved by the same offset.
+    fn split(&self, interval: Interval<T>) -> Vec<(Interval<T>, i128)> {
+        let mut parts = Vec::new();
+        let mut rest = RangeSet::from(interval);
+        for (source, offset) in self.disjoint_pieces() {
+            if let Some(part) = interval.intersect(&source) {
+                parts.push((part, offset));
+                rest = rest.difference(&RangeSet::from(part));
+            }
+        }
+        parts.extend(rest.intervals().iter().map(|&part| (part, 0)));
+        parts
+    }
+}
+
+impl<T: Point> Default for OffsetMap<T> {
+    fn default() -> Self {
+        OffsetMap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The seed-to-soil map of the 2023 day 5 example.
+    fn example() -> OffsetMap<u64> {
+        let mut map = OffsetMap::new();
+        map.push(Interval::new(98, 100), -48).unwrap();
+        map.push(Interval::new(50, 98), 2).unwrap();
+        map
+    }
+
+    #[test]
+    fn test_apply() {
+        let map = example();
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(98), 50);
+        assert_eq!(map.apply(14), 14);
+
+        let mut map = OffsetMap::new();
+        assert_eq!(map.push(Interval::new(250u8, 255), 5), Err(OutOfRange));
+        map.push(Interval::new(0, 10), 1).unwrap();
+        map.push(Interval::new(5, 20), 100).unwrap();
+        assert_eq!(map.apply(7), 8);
+        assert_eq!(map.apply(12), 112);
+    }
+
+    /// Check `map` against applying it value by value.
+    fn check(map: &OffsetMap<u64>, expected: impl Fn(u64) -> u64) {
+        for value in 0..120 {
+            assert_eq!(map.apply(value), expected(value), "value {}", value);
+        }
+    }
+
+    #[test]
+    fn test_then() {
+        let first = example();
+        let mut second = OffsetMap::new();
+        second.push(Interval::new(0, 15), 39).unwrap();
+        second.push(Interval::new(15, 52), -15).unwrap();
+        second.push(Interval::new(52, 54), -15).unwrap();
+
+        let composed = first.then(&second);
+        check(&composed, |value| second.apply(first.apply(value)));
+        // Pieces that cancel out are dropped
+        let inverse = first.inverse().unwrap();
+        assert!(first.then(&inverse).pieces().is_empty());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let map = example();
+        let inverse = map.inverse().unwrap();
+        check(&map.then(&inverse), |value| value);
+        check(&inverse.then(&map), |value| value);
+        assert_eq!(inverse.apply(50), 98);
+
+        // 0..5 is moved onto 5..10, which is also left in place
+        let mut map = OffsetMap::new();
+        map.push(Interval::new(0u32, 5), 5).unwrap();
+        assert_eq!(map.inverse(), None);
+    }
+
+    #[test]
+    fn test_preimage() {
+        let mut map = example();
+        map.push(Interval::new(0, 10), 100).unwrap();
+        let below = RangeSet::from(Interval::new(0, 55));
+        let preimage = map.preimage(&below);
+        for value in 0..120 {
+            assert_eq!(
+                preimage.contains(value),
+                below.contains(map.apply(value)),
+                "value {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_interval() {
+        let map = example();
+        let image = map.apply_interval(Interval::new(40, 100));
+        assert_eq!(image, RangeSet::from(Interval::new(40, 100)));
+
+        let image = map.apply_interval(Interval::new(97, 99));
+        assert_eq!(
+            image.intervals(),
+            [Interval::new(50, 51), Interval::new(99, 100)]
+        );
+
+        // Every value agrees with mapping it on its own
+        let image = map.apply_set(&RangeSet::from(Interval::new(0, 120)));
+        assert_eq!(image.len(), 120);
+        for value in 0..120 {
+            assert!(image.contains(map.apply(value)));
+        }
+    }
+}
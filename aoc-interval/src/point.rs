@@ -0,0 +1,27 @@
+//! Integer types that can be the end points of an interval.
+
+use std::fmt;
+
+/// Primitive integer type that fits in an `i128`, so that lengths and
+/// offsets can be computed without overflow.
+pub trait Point: Copy + Ord + fmt::Debug {
+    fn to_i128(self) -> i128;
+    /// The value, or `None` if it does not fit in this type.
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! point {
+    ($($t:ty)*) => {$(
+        impl Point for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+        }
+    )*};
+}
+
+point!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
@@ -0,0 +1,204 @@
+//! Sets of integers stored as disjoint intervals.
+
+use std::fmt;
+
+use crate::{Interval, Point};
+
+/// Set of integers, stored as sorted intervals that neither overlap nor touch.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Point> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Add all values of `interval`, merging it with the intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // First interval that is not entirely before `interval`, and the
+        // first that is entirely after it
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// Largest value in the set.
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().and_then(Interval::last)
+    }
+
+    /// The disjoint intervals, in increasing order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            intervals.extend(a.intersect(&b));
+            // Move past the interval that ends first, the other one may still
+            // overlap the next interval of the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { intervals }
+    }
+
+    /// Values in this set, but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+            // Skip the intervals of `other` that end before this one
+            while j < other.intervals.len() && other.intervals[j].end <= interval.start {
+                j += 1;
+            }
+            let mut k = j;
+            while let (Some(current), Some(cut)) = (rest, other.intervals.get(k)) {
+                if cut.start >= current.end {
+                    break;
+                }
+                let (below, _) = current.split_at(cut.start);
+                let (_, above) = current.split_at(cut.end);
+                intervals.extend(below);
+                rest = above;
+                k += 1;
+            }
+            intervals.extend(rest);
+        }
+        RangeSet { intervals }
+    }
+}
+
+impl<T: Point> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: Point> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Point> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Point> Extend<Interval<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> RangeSet<i32> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ranges = set(&[(0, 5), (10, 15), (20, 25)]);
+        assert_eq!(ranges.len(), 15);
+        assert!(ranges.contains(4) && !ranges.contains(5) && ranges.contains(10));
+
+        // Touching intervals are merged
+        ranges.insert(Interval::new(5, 7));
+        assert_eq!(ranges, set(&[(0, 7), (10, 15), (20, 25)]));
+        ranges.insert(Interval::new(12, 21));
+        assert_eq!(ranges, set(&[(0, 7), (10, 25)]));
+        ranges.insert(Interval::new(-3, -1));
+        assert_eq!(ranges.intervals()[0], Interval::new(-3, -1));
+        assert_eq!((ranges.min(), ranges.max()), (Some(-3), Some(24)));
+    }
+
+    #[test]
+    fn test_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 29)]);
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 29)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28), (29, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+}
@@ -0,0 +1,101 @@
+//! Chinese Remainder Theorem, for moduli that need not be coprime.
+
+use crate::{extended_gcd, Overflow, Signed};
+
+/// Smallest non-negative `x` with `x ≡ residue (mod modulus)` for all given
+/// `(residue, modulus)` pairs, together with the modulus of all solutions,
+/// i.e. the least common multiple of the moduli. Returns `None` if the
+/// congruences contradict each other, which is possible when the moduli are
+/// not coprime. Panics if a modulus is not positive.
+pub fn crt<T: Signed>(
+    congruences: impl IntoIterator<Item = (T, T)>,
+) -> Result<Option<(T, T)>, Overflow> {
+    let (mut x, mut modulus) = (T::ZERO, T::ONE);
+    for (residue, m) in congruences {
+        assert!(m > T::ZERO, "Modulus {} is not positive", m);
+        let residue = residue.rem_euclid(m)?;
+
+        // modulus * p + m * q = g, so x + modulus * k solves both congruences
+        // for k = (residue - x) / g * p, which only exists if g divides the
+        // difference
+        let (g, p, _) = extended_gcd(modulus, m)?;
+        let difference = residue.sub(x)?;
+        if difference.rem_euclid(g)? != T::ZERO {
+            return Ok(None);
+        }
+        let step = m.div(g)?;
+        let k = mul_mod(
+            difference.div(g)?.rem_euclid(step)?,
+            p.rem_euclid(step)?,
+            step,
+        )?;
+        let lcm = modulus.mul(step)?;
+        x = x.add(modulus.mul(k)?)?.rem_euclid(lcm)?;
+        modulus = lcm;
+    }
+    Ok(Some((x, modulus)))
+}
+
+/// `a * b mod m` for `a` and `b` in `0..m`, by doubling and adding, so that
+/// no intermediate value is larger than `m`.
+fn mul_mod<T: Signed>(mut a: T, mut b: T, m: T) -> Result<T, Overflow> {
+    // a + b mod m for a and b in 0..m, without computing a + b
+    let add_mod = |a: T, b: T| -> Result<T, Overflow> {
+        let room = m.sub(b)?;
+        if a >= room {
+            a.sub(room)
+        } else {
+            a.add(b)
+        }
+    };
+    let mut product = T::ZERO;
+    while b > T::ZERO {
+        if b.rem_euclid(T::TWO)? == T::ONE {
+            product = add_mod(product, a)?;
+        }
+        a = add_mod(a, a)?;
+        b = b.div(T::TWO)?;
+    }
+    Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coprime() {
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Ok(Some((23, 105))));
+        assert_eq!(crt([(-1i32, 4)]), Ok(Some((3, 4))));
+        assert_eq!(crt(Vec::<(i8, i8)>::new()), Ok(Some((0, 1))));
+    }
+
+    #[test]
+    fn test_not_coprime() {
+        assert_eq!(crt([(2i64, 4), (4, 6)]), Ok(Some((10, 12))));
+        assert_eq!(crt([(0i64, 6), (0, 10), (0, 15)]), Ok(Some((0, 30))));
+        assert_eq!(crt([(1i64, 4), (2, 6)]), Ok(None));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(crt([(1i8, 11), (2, 13)]), Err(Overflow));
+        assert!(crt([(1i128, 1 << 62), (2, (1 << 62) - 1)])
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_near_limit() {
+        // The combined modulus 3 * 2^61 fits in i64, but the factors of `k`
+        // are close to 2^61
+        let (x, modulus) = crt([(2i64, 3), ((1 << 61) - 1, 1 << 61)]).unwrap().unwrap();
+        assert_eq!(modulus, 3 << 61);
+        assert_eq!((x % 3, x % (1 << 61)), (2, (1 << 61) - 1));
+
+        let (x, modulus) = crt([(1i64, 2), ((1 << 61) - 3, 1 << 61)]).unwrap().unwrap();
+        assert_eq!((x, modulus), ((1 << 61) - 3, 1 << 61));
+
+        assert_eq!(mul_mod(i64::MAX - 1, i64::MAX - 2, i64::MAX), Ok(2));
+    }
+}
@@ -0,0 +1,79 @@
+//! Greatest common divisor and least common multiple.
+
+use crate::{Int, Overflow, Signed};
+
+/// Greatest common divisor of the absolute values of `a` and `b`, where
+/// `gcd(0, 0)` is zero.
+pub fn gcd<T: Int>(a: T, b: T) -> Result<T, Overflow> {
+    let (mut a, mut b) = (a.abs()?, b.abs()?);
+    while b != T::ZERO {
+        (a, b) = (b, a.rem_euclid(b)?);
+    }
+    Ok(a)
+}
+
+/// Least common multiple of the absolute values of `a` and `b`, which is
+/// zero if either of them is.
+pub fn lcm<T: Int>(a: T, b: T) -> Result<T, Overflow> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    a.abs()?.div(gcd(a, b)?)?.mul(b.abs()?)
+}
+
+/// Least common multiple of all `numbers`, which is one if there are none.
+pub fn lcm_of<T: Int>(numbers: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |acc, number| lcm(acc, number))
+}
+
+/// Greatest common divisor `g` of `a` and `b`, with coefficients `x` and `y`
+/// such that `a * x + b * y = g`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> Result<(T, T, T), Overflow> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r.div(r)?;
+        (old_r, r) = (r, old_r.sub(q.mul(r)?)?);
+        (old_x, x) = (x, old_x.sub(q.mul(x)?)?);
+        (old_y, y) = (y, old_y.sub(q.mul(y)?)?);
+    }
+    if old_r < T::ZERO {
+        Ok((old_r.neg()?, old_x.neg()?, old_y.neg()?))
+    } else {
+        Ok((old_r, old_x, old_y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12u64, 18), Ok(6));
+        assert_eq!(gcd(-12i32, 18), Ok(6));
+        assert_eq!(gcd(0u8, 0), Ok(0));
+        assert_eq!(gcd(i64::MIN, 2), Err(Overflow));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4u32, 6), Ok(12));
+        assert_eq!(lcm(0u32, 6), Ok(0));
+        assert_eq!(lcm_of([2usize, 3, 4]), Ok(12));
+        assert_eq!(lcm_of(Vec::<u8>::new()), Ok(1));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), Err(Overflow));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240i64, 46), (-7, 3), (0, 5), (5, 0), (12, -18)] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(g, gcd(a, b).unwrap());
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Primitive integer types the functions of this crate are generic over.
+
+use std::fmt;
+
+/// The result of an operation does not fit in its integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Checked arithmetic of a primitive integer type, returning [`Overflow`]
+/// instead of an `Option`.
+pub trait Int: Copy + Ord + fmt::Debug + fmt::Display {
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+
+    fn add(self, rhs: Self) -> Result<Self, Overflow>;
+    fn sub(self, rhs: Self) -> Result<Self, Overflow>;
+    fn mul(self, rhs: Self) -> Result<Self, Overflow>;
+    /// Division rounding towards zero. Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Result<Self, Overflow>;
+    /// Remainder that is never negative. Panics if `rhs` is zero.
+    fn rem_euclid(self, rhs: Self) -> Result<Self, Overflow>;
+    fn abs(self) -> Result<Self, Overflow>;
+    /// Integer square root, or `None` for negative numbers.
+    fn isqrt(self) -> Option<Self>;
+}
+
+/// Signed integer type.
+pub trait Signed: Int {
+    fn neg(self) -> Result<Self, Overflow>;
+}
+
+/// Methods that are the same for signed and unsigned types.
+macro_rules! checked {
+    () => {
+        const ZERO: Self = 0;
+        const ONE: Self = 1;
+        const TWO: Self = 2;
+
+        fn add(self, rhs: Self) -> Result<Self, Overflow> {
+            self.checked_add(rhs).ok_or(Overflow)
+        }
+
+        fn sub(self, rhs: Self) -> Result<Self, Overflow> {
+            self.checked_sub(rhs).ok_or(Overflow)
+        }
+
+        fn mul(self, rhs: Self) -> Result<Self, Overflow> {
+            self.checked_mul(rhs).ok_or(Overflow)
+        }
+
+        fn div(self, rhs: Self) -> Result<Self, Overflow> {
+            assert!(rhs != 0, "Division by zero");
+            self.checked_div(rhs).ok_or(Overflow)
+        }
+
+        fn rem_euclid(self, rhs: Self) -> Result<Self, Overflow> {
+            assert!(rhs != 0, "Division by zero");
+            self.checked_rem_euclid(rhs).ok_or(Overflow)
+        }
+    };
+}
+
+macro_rules! unsigned {
+    ($($t:ty)*) => {$(
+        impl Int for $t {
+            checked!();
+
+            fn abs(self) -> Result<Self, Overflow> {
+                Ok(self)
+            }
+
+            fn isqrt(self) -> Option<Self> {
+                Some(<$t>::isqrt(self))
+            }
+        }
+    )*};
+}
+
+macro_rules! signed {
+    ($($t:ty)*) => {$(
+        impl Int for $t {
+            checked!();
+
+            fn abs(self) -> Result<Self, Overflow> {
+                self.checked_abs().ok_or(Overflow)
+            }
+
+            fn isqrt(self) -> Option<Self> {
+                self.checked_isqrt()
+            }
+        }
+
+        impl Signed for $t {
+            fn neg(self) -> Result<Self, Overflow> {
+                self.checked_neg().ok_or(Overflow)
+            }
+        }
+    )*};
+}
+
+unsigned!(u8 u16 u32 u64 u128 usize);
+signed!(i8 i16 i32 i64 i128 isize);
@@ -0,0 +1,12 @@
+//! Number theory shared by the days. All arithmetic is checked, and an
+//! overflow is reported as [`Overflow`] instead of wrapping silently.
+
+pub mod crt;
+pub mod gcd;
+pub mod int;
+pub mod roots;
+
+pub use crt::crt;
+pub use gcd::{extended_gcd, gcd, lcm, lcm_of};
+pub use int::{Int, Overflow, Signed};
+pub use roots::{isqrt, quadratic_roots};
@@ -0,0 +1,59 @@
+//! Integer square roots and roots of quadratics.
+
+use crate::{Int, Overflow, Signed};
+
+/// Largest integer whose square is at most `n`, or `None` if `n` is negative.
+pub fn isqrt<T: Int>(n: T) -> Option<T> {
+    n.isqrt()
+}
+
+/// Integer roots of `a * x² + b * x + c`, in increasing order. Roots that are
+/// not integers are left out. Panics if `a` is zero.
+pub fn quadratic_roots<T: Signed>(a: T, b: T, c: T) -> Result<Vec<T>, Overflow> {
+    assert!(a != T::ZERO, "Not a quadratic");
+    // The roots (-b ± √d) / 2a are rational only if the discriminant d is a
+    // square, and integers only if 2a divides the numerator
+    let discriminant = b.mul(b)?.sub(T::TWO.mul(T::TWO)?.mul(a)?.mul(c)?)?;
+    let Some(root) = isqrt(discriminant) else {
+        return Ok(Vec::new());
+    };
+    if root.mul(root)? != discriminant {
+        return Ok(Vec::new());
+    }
+
+    let denominator = T::TWO.mul(a)?;
+    let mut roots = Vec::new();
+    for numerator in [b.neg()?.sub(root)?, b.neg()?.add(root)?] {
+        if numerator.rem_euclid(denominator)? == T::ZERO {
+            roots.push(numerator.div(denominator)?);
+        }
+    }
+    roots.sort();
+    roots.dedup();
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0u64), Some(0));
+        assert_eq!(isqrt(24u64), Some(4));
+        assert_eq!(isqrt(25i32), Some(5));
+        assert_eq!(isqrt(-1i32), None);
+        assert_eq!(isqrt(u128::MAX), Some(u64::MAX as u128));
+    }
+
+    #[test]
+    fn test_quadratic_roots() {
+        assert_eq!(quadratic_roots(1i64, -5, 6), Ok(vec![2, 3]));
+        assert_eq!(quadratic_roots(-1i64, 5, -6), Ok(vec![2, 3]));
+        assert_eq!(quadratic_roots(1i64, -4, 4), Ok(vec![2]));
+        assert_eq!(quadratic_roots(2i64, -3, 1), Ok(vec![1]));
+        assert_eq!(quadratic_roots(1i64, 0, -2), Ok(vec![]));
+        assert_eq!(quadratic_roots(1i64, 0, 1), Ok(vec![]));
+        assert_eq!(quadratic_roots(1i8, 100, 1), Err(Overflow));
+    }
+}
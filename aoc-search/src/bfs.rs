@@ -0,0 +1,97 @@
+//! Breadth-first search, for graphs where every step costs the same.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::{reconstruct, Path};
+
+/// Shortest path, in steps, from `start` to a state satisfying `is_goal`.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    // Every state seen, with the index of the state it was reached from
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut seen = HashMap::from([(start, 0usize)]);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    let mut visited = 0;
+    while let Some((i, steps)) = queue.pop_front() {
+        visited += 1;
+        if is_goal(&nodes[i].0) {
+            return Some(Path {
+                cost: steps,
+                states: reconstruct(&nodes, i),
+                visited,
+            });
+        }
+        for next in successors(&nodes[i].0) {
+            if let Entry::Vacant(entry) = seen.entry(next) {
+                let j = nodes.len();
+                nodes.push((entry.key().clone(), i));
+                entry.insert(j);
+                queue.push_back((j, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// All states reachable from `start`, with the number of steps needed to
+/// reach them.
+pub fn reachable<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, n)) = queue.pop_front() {
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = steps.entry(next.clone()) {
+                entry.insert(n + 1);
+                queue.push_back((next, n + 1));
+            }
+        }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers that can be reached by adding one or doubling.
+    fn successors(&n: &u32) -> Vec<u32> {
+        vec![n + 1, n * 2]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(1, successors, |&n| n == 10).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states.len(), 5);
+        assert_eq!(path.states.first(), Some(&1));
+        assert_eq!(path.states.last(), Some(&10));
+        assert!(path.visited > 1);
+
+        assert_eq!(bfs(3, |&n| [n + 1], |&n| n == 3).unwrap().states, [3]);
+        assert_eq!(
+            bfs(3, |&n: &u32| (n < 5).then_some(n + 1), |&n| n == 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reachable() {
+        let steps = reachable(0, |&n: &u32| (n < 4).then_some(n + 1));
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[&4], 4);
+    }
+}
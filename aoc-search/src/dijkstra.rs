@@ -0,0 +1,127 @@
+//! Dijkstra's algorithm and A*, for graphs with weighted steps.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::{reconstruct, Path};
+
+/// Cheapest path from `start` to a state satisfying `is_goal`, where
+/// `successors` gives the next states with the cost of the step to them.
+/// Costs must not be negative, and the default of `C` must be zero.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Same as [`dijkstra`], but guided by a `heuristic` estimating the remaining
+/// cost to a goal. The path is the cheapest one if the heuristic never
+/// overestimates, and never decreases by more than the cost of a step.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Every state seen, with the index of the state it was reached from, and
+    // its lowest known cost
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut costs = vec![C::default()];
+    let mut done = vec![false];
+    let mut seen = HashMap::from([(start, 0usize)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&nodes[0].0), 0))]);
+
+    let mut visited = 0;
+    while let Some(Reverse((_, i))) = queue.pop() {
+        // Already expanded with a lower cost
+        if done[i] {
+            continue;
+        }
+        done[i] = true;
+        visited += 1;
+
+        if is_goal(&nodes[i].0) {
+            return Some(Path {
+                cost: costs[i],
+                states: reconstruct(&nodes, i),
+                visited,
+            });
+        }
+
+        for (next, step) in successors(&nodes[i].0) {
+            let cost = costs[i] + step;
+            let j = match seen.entry(next) {
+                Entry::Vacant(entry) => {
+                    let j = nodes.len();
+                    nodes.push((entry.key().clone(), i));
+                    costs.push(cost);
+                    done.push(false);
+                    entry.insert(j);
+                    j
+                }
+                Entry::Occupied(entry) => {
+                    let j = *entry.get();
+                    if done[j] || cost >= costs[j] {
+                        continue;
+                    }
+                    nodes[j].1 = i;
+                    costs[j] = cost;
+                    j
+                }
+            };
+            queue.push(Reverse((cost + heuristic(&nodes[j].0), j)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Grid of step costs, moving between the four neighbours of a cell.
+    const COSTS: [[u32; 4]; 3] = [[1, 9, 1, 1], [1, 9, 1, 9], [1, 1, 1, 1]];
+
+    fn successors(&(x, y): &(usize, usize)) -> Vec<((usize, usize), u32)> {
+        [(0, 1), (2, 1), (1, 0), (1, 2)]
+            .into_iter()
+            .filter_map(|(dx, dy)| Some(((x + dx).checked_sub(1)?, (y + dy).checked_sub(1)?)))
+            .filter(|&(x, y)| x < 4 && y < 3)
+            .map(|(x, y)| ((x, y), COSTS[y][x]))
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra((0, 0), successors, |&pos| pos == (3, 0)).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states.len(), 8);
+        assert_eq!(path.states[2], (0, 2));
+        assert_eq!(dijkstra((0, 0), successors, |&pos| pos == (5, 5)), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (3usize, 0usize);
+        let distance = |&(x, y): &(usize, usize)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u32;
+        let path = astar((0, 0), successors, distance, |&pos| pos == goal).unwrap();
+        let expected = dijkstra((0, 0), successors, |&pos| pos == goal).unwrap();
+        assert_eq!(path.cost, expected.cost);
+        assert!(path.visited <= expected.visited);
+    }
+}
@@ -0,0 +1,32 @@
+//! Graph searches over a state type defined by the caller. States are found
+//! through a successor function, so the graph never has to be built up front.
+
+pub mod bfs;
+pub mod dijkstra;
+
+pub use bfs::{bfs, reachable};
+pub use dijkstra::{astar, dijkstra};
+
+/// Cheapest path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// Total cost of the path, for a breadth-first search the number of steps.
+    pub cost: C,
+    /// All states from the start to the goal, both included.
+    pub states: Vec<S>,
+    /// Number of states expanded before the goal was reached.
+    pub visited: usize,
+}
+
+/// Walk the parent links from `end` back to the start. A start is its own
+/// parent.
+fn reconstruct<S: Clone>(nodes: &[(S, usize)], end: usize) -> Vec<S> {
+    let mut path = vec![nodes[end].0.clone()];
+    let mut current = end;
+    while nodes[current].1 != current {
+        current = nodes[current].1;
+        path.push(nodes[current].0.clone());
+    }
+    path.reverse();
+    path
+}
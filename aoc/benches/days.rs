@@ -0,0 +1,105 @@
+//! Benchmarks of all days, timing parsing separately from solving each part.
+//! Every day is run on its example files and on the puzzle input, if present.
+//!
+//! Run a single day with e.g. `cargo bench --bench days -- day05`.
+
+use std::fs;
+
+use aoc::days;
+use aoc_core::input::{self, Source};
+use aoc_core::{Part, Solution};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Inputs of a day as (name, content), i.e. all `example*.txt` files and the
+/// puzzle input.
+fn inputs(day: u8) -> Vec<(String, String)> {
+    let dir = days::workspace_root().join(days::crate_name(day));
+    let mut paths = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name == input::INPUT_FILE
+                        || (name.starts_with("example") && name.ends_with(".txt"))
+                })
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            // Read like the runner does, with normalized line endings
+            let content = Source::File(path.clone()).read().ok()?;
+            Some((name, content))
+        })
+        .collect()
+}
+
+/// Examples a part can not handle at all, e.g. day 20 part 2 relies on the
+/// structure of the real input and never finishes on the examples.
+const UNSUPPORTED: &[(u8, Part, &str)] = &[(20, Part::Two, "example"), (20, Part::Two, "example2")];
+
+fn supported(day: u8, part: Part, name: &str) -> bool {
+    !UNSUPPORTED.contains(&(day, part, name))
+}
+
+fn bench<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    // Some solutions take seconds on the real input
+    group.sample_size(10);
+
+    for (name, input) in inputs(S::DAY) {
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("Skipping day{:02}/{}: {}", S::DAY, name, err);
+                continue;
+            }
+        };
+        group.bench_with_input(BenchmarkId::new("parse", &name), &input, |b, input| {
+            b.iter(|| S::parse(black_box(input)))
+        });
+
+        if supported(S::DAY, Part::One, &name) {
+            group.bench_with_input(BenchmarkId::new("part1", &name), &parsed, |b, parsed| {
+                b.iter(|| S::part1(black_box(parsed)))
+            });
+        }
+        if supported(S::DAY, Part::Two, &name) {
+            group.bench_with_input(BenchmarkId::new("part2", &name), &parsed, |b, parsed| {
+                b.iter(|| S::part2(black_box(parsed)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench::<day01::Day01>,
+    bench::<day02::Day02>,
+    bench::<day03::Day03>,
+    bench::<day04::Day04>,
+    bench::<day05::Day05>,
+    bench::<day06::Day06>,
+    bench::<day07::Day07>,
+    bench::<day08::Day08>,
+    bench::<day09::Day09>,
+    bench::<day10::Day10>,
+    bench::<day11::Day11>,
+    bench::<day12::Day12>,
+    bench::<day13::Day13>,
+    bench::<day14::Day14>,
+    bench::<day15::Day15>,
+    bench::<day16::Day16>,
+    bench::<day17::Day17>,
+    bench::<day18::Day18>,
+    bench::<day19::Day19>,
+    bench::<day20::Day20>,
+);
+criterion_main!(benches);
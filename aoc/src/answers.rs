@@ -0,0 +1,136 @@
+//! Accepted answers of a day, stored in `dayXX/answers.txt`. Each line holds
+//! the answers of both parts for one input file of the crate:
+//!
+//! ```text
+//! # input, part 1, part 2
+//! example.txt 35 46
+//! input.txt 174137457 -
+//! ```
+//!
+//! A `-` marks an answer that is not known yet. Empty lines and lines starting
+//! with `#` are ignored.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use aoc_core::Part;
+
+/// Name of the answers file inside each day crate.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Accepted answers of both parts for one input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Input file, relative to the day crate.
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Entry {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// All accepted answers of a day, in file order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub entries: Vec<Entry>,
+}
+
+impl Answers {
+    /// Load the answers of a day crate. A missing file means no answers.
+    pub fn load(crate_dir: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        let path = crate_dir.as_ref().join(ANSWERS_FILE);
+        match fs::read_to_string(&path) {
+            Ok(content) => Answers::parse(&content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(AnswersError::Io(err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, AnswersError> {
+        let mut entries: Vec<Entry> = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [input, part1, part2] = fields[..] else {
+                return Err(AnswersError::Syntax {
+                    line: i + 1,
+                    message: "expected an input file and two answers".to_string(),
+                });
+            };
+            if entries.iter().any(|entry| entry.input == input) {
+                return Err(AnswersError::Syntax {
+                    line: i + 1,
+                    message: format!("duplicate answers for {}", input),
+                });
+            }
+
+            let answer = |answer: &str| (answer != "-").then(|| answer.to_string());
+            entries.push(Entry {
+                input: input.to_string(),
+                part1: answer(part1),
+                part2: answer(part2),
+            });
+        }
+        Ok(Answers { entries })
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Syntax { line: usize, message: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "Could not read {}: {}", ANSWERS_FILE, err),
+            AnswersError::Syntax { line, message } => {
+                write!(f, "Invalid {} on line {}: {}", ANSWERS_FILE, line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers =
+            Answers::parse("# input, part 1, part 2\n\nexample.txt 35 46\ninput.txt 7 -\n")
+                .unwrap();
+        assert_eq!(answers.entries.len(), 2);
+        assert_eq!(answers.entries[0].answer(Part::Two), Some("46"));
+        assert_eq!(answers.entries[1].input, "input.txt");
+        assert_eq!(answers.entries[1].answer(Part::Two), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            Answers::parse("a.txt 1\n"),
+            Err(AnswersError::Syntax { line: 1, .. })
+        ));
+        assert!(matches!(
+            Answers::parse("a.txt 1 2\na.txt 3 4\n"),
+            Err(AnswersError::Syntax { line: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{input, DynSolution};
+
+/// Last day of the calendar.
+pub const LAST_DAY: u8 = 25;
+
+/// All days that have a solution, ordered by day.
+pub static DAYS: [&dyn DynSolution; 20] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+];
+
+/// Find a day by its number.
+pub fn day(number: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|day| day.day() == number)
+}
+
+/// Name of the crate containing the solution of a day, e.g. `day05`.
+pub fn crate_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Path to the puzzle input of a day, i.e. `dayXX/input.txt` in the workspace
+/// root.
+pub fn input_path(day: u8) -> PathBuf {
+    input::default_path(workspace_root().join(crate_name(day)))
+}
+
+/// Root of the workspace, i.e. the directory containing all `dayXX` crates.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Expected crate to be inside the workspace")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Part;
+
+    use super::*;
+
+    #[test]
+    fn test_days_ordered() {
+        assert!(DAYS
+            .iter()
+            .enumerate()
+            .all(|(i, d)| d.day() as usize == i + 1));
+    }
+
+    #[test]
+    fn test_example() {
+        let day = day(5).unwrap();
+        let input = include_str!("../../day05/example.txt");
+        assert_eq!(day.solve(Part::One, input).unwrap(), "35");
+        assert_eq!(day.solve(Part::Two, input).unwrap(), "46");
+    }
+}
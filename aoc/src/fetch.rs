@@ -0,0 +1,424 @@
+//! Download the puzzle input and the first example of a day into its crate.
+//!
+//! Files already present in the crate are never downloaded again, so running
+//! `aoc fetch` repeatedly only sends requests for what is missing. Requests
+//! are spaced out by a [`Throttle`] and identify the repository in their
+//! `User-Agent`, as asked by the site's automation rules. The HTTP requests
+//! themselves go through the [`Http`] trait, so tests can use a local server
+//! instead of the site.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::days;
+
+/// Site the puzzles are fetched from.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2023;
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// File in the workspace root holding the session token, used when
+/// [`SESSION_VAR`] is not set.
+pub const SESSION_FILE: &str = ".session";
+
+/// Default minimum time between two requests.
+pub const INTERVAL: Duration = Duration::from_secs(3);
+
+const USER_AGENT: &str = "github.com/davaxe/AdventOfCode2023 (aoc fetch)";
+
+/// Backend sending the requests.
+pub trait Http {
+    /// Send a GET request to `url`, authenticated with the `session` token,
+    /// and return the body of the response.
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+}
+
+/// [`Http`] backend using `ureq`.
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Default for Ureq {
+    fn default() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Ureq { agent }
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => FetchError::Status {
+                    url: url.to_string(),
+                    status,
+                },
+                ureq::Error::Transport(err) => FetchError::Http(err.to_string()),
+            })?;
+        response.into_string().map_err(FetchError::Io)
+    }
+}
+
+/// Keeps a minimum interval between consecutive requests.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl Throttle {
+    pub fn new(interval: Duration) -> Self {
+        Throttle {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Block until a request may be sent, and record it as sent.
+    pub fn wait(&mut self) {
+        if let Some(last) = self.last {
+            if let Some(remaining) = self.interval.checked_sub(last.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+        self.last = Some(Instant::now());
+    }
+}
+
+/// Result of fetching a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The file was already in the crate and was left untouched.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl fmt::Display for Fetched {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fetched::Cached(path) => write!(f, "{} already exists", path.display()),
+            Fetched::Downloaded(path) => write!(f, "Downloaded {}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session token in [`SESSION_VAR`] or [`SESSION_FILE`].
+    MissingSession,
+    /// The crate of the day does not exist yet.
+    MissingCrate(PathBuf),
+    Status {
+        url: String,
+        status: u16,
+    },
+    Http(String),
+    /// The puzzle page contains no example.
+    NoExample(u8),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "No session token, set {} or write it to {}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            FetchError::MissingCrate(dir) => write!(
+                f,
+                "{} does not exist, create it with `aoc new` first",
+                dir.display()
+            ),
+            FetchError::Status { url, status } => match status {
+                400 | 401 => write!(f, "{} refused the session token ({})", url, status),
+                404 => write!(f, "{} is not available (yet)", url),
+                status => write!(f, "Request to {} failed with status {}", url, status),
+            },
+            FetchError::Http(err) => write!(f, "Request failed: {}", err),
+            FetchError::NoExample(day) => write!(f, "No example found for day {}", day),
+            FetchError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+/// Read the session token from [`SESSION_VAR`], or else from [`SESSION_FILE`]
+/// in `root`.
+pub fn session(root: &Path) -> Result<String, FetchError> {
+    let token = match std::env::var(SESSION_VAR) {
+        Ok(token) => token,
+        Err(_) => match fs::read_to_string(root.join(SESSION_FILE)) {
+            Ok(token) => token,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(FetchError::Io(err)),
+        },
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        Err(FetchError::MissingSession)
+    } else {
+        Ok(token.to_string())
+    }
+}
+
+/// Downloads files of the days into their crates in a workspace.
+pub struct Fetcher<H> {
+    http: H,
+    session: String,
+    base_url: String,
+    root: PathBuf,
+    throttle: Throttle,
+}
+
+impl<H: Http> Fetcher<H> {
+    /// Fetcher for this workspace, downloading from [`BASE_URL`].
+    pub fn new(http: H, session: impl Into<String>) -> Self {
+        Fetcher {
+            http,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+            root: days::workspace_root().to_path_buf(),
+            throttle: Throttle::new(INTERVAL),
+        }
+    }
+
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Workspace containing the day crates.
+    pub fn root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.throttle = Throttle::new(interval);
+        self
+    }
+
+    /// Fetch the personal puzzle input into `dayXX/input.txt`.
+    pub fn input(&mut self, day: u8) -> Result<Fetched, FetchError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        self.fetch(day, "input.txt", &url, |body| Some(body.to_string()))
+    }
+
+    /// Fetch the first example of the puzzle description into
+    /// `dayXX/example.txt`.
+    pub fn example(&mut self, day: u8) -> Result<Fetched, FetchError> {
+        let url = format!("{}/{}/day/{}", self.base_url, YEAR, day);
+        self.fetch(day, "example.txt", &url, example)
+    }
+
+    fn fetch(
+        &mut self,
+        day: u8,
+        file: &str,
+        url: &str,
+        extract: impl FnOnce(&str) -> Option<String>,
+    ) -> Result<Fetched, FetchError> {
+        let dir = self.root.join(days::crate_name(day));
+        if !dir.is_dir() {
+            return Err(FetchError::MissingCrate(dir));
+        }
+        // The template creates an empty example, which does not count
+        let path = dir.join(file);
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        self.throttle.wait();
+        let body = self.http.get(url, &self.session)?;
+        let content = extract(&body).ok_or(FetchError::NoExample(day))?;
+
+        // Write to a temporary file first, so an interrupted write is not
+        // mistaken for a cached file on the next run
+        let partial = dir.join(format!("{}.part", file));
+        fs::write(&partial, content)?;
+        fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// Extract the first example, i.e. the first `<pre><code>` block, from the
+/// HTML of a puzzle page.
+fn example(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + html[start..].find("</code></pre>")?;
+    let block = &html[start..end];
+
+    // Remove markup such as `<em>` used for highlighting
+    let mut text = String::with_capacity(block.len());
+    let mut in_tag = false;
+    for c in block.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    /// Serves fixed pages and records the requested urls.
+    #[derive(Default)]
+    struct Fake {
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Http for &Fake {
+        fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+            assert_eq!(session, "token");
+            self.requests.borrow_mut().push(url.to_string());
+            if url.ends_with("/input") {
+                Ok("1\n2\n".to_string())
+            } else {
+                Ok(PAGE.to_string())
+            }
+        }
+    }
+
+    const PAGE: &str = "<article><p>For example:</p>\n\
+        <pre><code>a -&gt; <em>b</em>\n&lt;c&gt; &amp; d\n</code></pre>\n\
+        <pre><code>second\n</code></pre></article>";
+
+    fn workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        fs::create_dir_all(root.join("day05")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(example(PAGE).unwrap(), "a -> b\n<c> & d\n");
+        assert_eq!(example("<p>No example</p>"), None);
+    }
+
+    #[test]
+    fn test_cache() {
+        let root = workspace("cache");
+        let http = Fake::default();
+        let mut fetcher = Fetcher::new(&http, "token")
+            .base_url("http://localhost/")
+            .root(&root)
+            .interval(Duration::ZERO);
+
+        let path = root.join("day05/input.txt");
+        assert_eq!(fetcher.input(5).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fetcher.input(5).unwrap(), Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n");
+
+        // An empty example, as created by the template, is replaced
+        let path = root.join("day05/example.txt");
+        fs::write(&path, "").unwrap();
+        assert_eq!(
+            fetcher.example(5).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fetcher.example(5).unwrap(), Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a -> b\n<c> & d\n");
+
+        assert!(matches!(fetcher.input(6), Err(FetchError::MissingCrate(_))));
+        assert_eq!(
+            *http.requests.borrow(),
+            [
+                "http://localhost/2023/day/5/input",
+                "http://localhost/2023/day/5"
+            ]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut throttle = Throttle::new(Duration::from_millis(50));
+        let start = Instant::now();
+        throttle.wait();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        throttle.wait();
+        throttle.wait();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_ureq() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let handle = thread::spawn(move || {
+            let mut cookies = Vec::new();
+            for request in server.incoming_requests().take(2) {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+                cookies.push(cookie);
+                let response = match request.url() {
+                    "/2023/day/5/input" => tiny_http::Response::from_string("seeds: 1 2\n"),
+                    _ => tiny_http::Response::from_string("").with_status_code(400),
+                };
+                request.respond(response).unwrap();
+            }
+            cookies
+        });
+
+        let root = workspace("ureq");
+        let mut fetcher = Fetcher::new(Ureq::default(), "token")
+            .base_url(format!("http://127.0.0.1:{}", port))
+            .root(&root)
+            .interval(Duration::ZERO);
+        assert!(matches!(fetcher.input(5), Ok(Fetched::Downloaded(_))));
+        assert_eq!(
+            fs::read_to_string(root.join("day05/input.txt")).unwrap(),
+            "seeds: 1 2\n"
+        );
+        assert!(matches!(
+            fetcher.example(5),
+            Err(FetchError::Status { status: 400, .. })
+        ));
+        assert!(!root.join("day05/example.txt").exists());
+
+        let cookies = handle.join().unwrap();
+        assert!(cookies
+            .iter()
+            .all(|cookie| cookie.as_deref() == Some("session=token")));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
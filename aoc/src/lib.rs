@@ -0,0 +1,8 @@
+pub mod answers;
+pub mod days;
+pub mod fetch;
+pub mod scaffold;
+pub mod selection;
+pub mod verify;
+
+pub use days::DAYS;
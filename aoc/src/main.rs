@@ -0,0 +1,259 @@
+use std::process::ExitCode;
+
+use aoc::fetch::{self, Fetcher, Ureq};
+use aoc::scaffold::{self, Options};
+use aoc::verify::{self, Outcome};
+use aoc::{days, selection, DAYS};
+use aoc_core::input::Source;
+use aoc_core::Part;
+
+const USAGE: &str = "\
+Usage: aoc [DAYS] [PARTS] [--input PATH]
+       aoc verify [DAYS]
+       aoc new DAY [--title TITLE] [--parser]
+       aoc fetch [DAYS]
+
+Run the solutions for the selected days and parts. With `verify`, rerun the
+solutions on every input in `dayXX/answers.txt` and report answers that no
+longer match. With `new`, create the crate `dayXX` from `day_template`, with
+an optional nom parser module, and add it to the workspace members. With
+`fetch`, download the input and the first example of the selected days into
+`dayXX/input.txt` and `dayXX/example.txt`, unless they already exist, where
+`all` selects every day that has a crate. The session token is read from
+`AOC_SESSION` or the file `.session`.
+
+Arguments:
+  DAYS   Days to run, e.g. `5`, `1-20`, `1,3,5-7` or `all` (default: all)
+  PARTS  Parts to run, e.g. `1`, `2` or `all` (default: all)
+
+Options:
+  -i, --input PATH  Read the input from PATH, or from stdin if PATH is `-`.
+                    Only allowed when a single day is selected. By default
+                    the input is read from `dayXX/input.txt`.";
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    if args.first().is_some_and(|arg| arg == "verify") {
+        return verify(&args[1..]);
+    }
+    if args.first().is_some_and(|arg| arg == "new") {
+        return new(args.split_off(1));
+    }
+    if args.first().is_some_and(|arg| arg == "fetch") {
+        return fetch(&args[1..]);
+    }
+
+    let input_arg = match args.iter().position(|arg| arg == "-i" || arg == "--input") {
+        Some(i) if i + 1 < args.len() => {
+            let path = args.remove(i + 1);
+            args.remove(i);
+            Some(path)
+        }
+        Some(_) => {
+            eprintln!("Missing value for --input\n\n{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+    if args.len() > 2 {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let selected = args.first().map_or("all", String::as_str);
+    let days = match selection::parse(selected, DAYS.len() as u8) {
+        Ok(days) => days,
+        Err(err) => {
+            eprintln!("Invalid days: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = match selection::parse(args.get(1).map_or("all", String::as_str), 2) {
+        Ok(parts) => parts
+            .into_iter()
+            .filter_map(Part::from_number)
+            .collect::<Vec<_>>(),
+        Err(err) => {
+            eprintln!("Invalid parts: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if input_arg.is_some() && days.len() != 1 {
+        eprintln!("--input can only be used when a single day is selected");
+        return ExitCode::FAILURE;
+    }
+
+    let mut success = true;
+    for day in days.into_iter().filter_map(days::day) {
+        println!("Day {:02}: {}", day.day(), day.title());
+        let source = Source::from_arg(input_arg.as_deref(), days::input_path(day.day()));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("  {}", err);
+                success = false;
+                continue;
+            }
+        };
+
+        for &part in &parts {
+            match day.solve(part, &input) {
+                Ok(answer) => println!("  Part {}: {}", part, answer),
+                Err(err) => {
+                    println!("  Part {}: {}", part, err);
+                    success = false;
+                }
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(args: &[String]) -> ExitCode {
+    if args.len() > 1 {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+    let days = match selection::parse(args.first().map_or("all", String::as_str), DAYS.len() as u8)
+    {
+        Ok(days) => days,
+        Err(err) => {
+            eprintln!("Invalid days: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut correct, mut wrong, mut skipped) = (0, 0, 0);
+    for day in days.into_iter().filter_map(days::day) {
+        let checks = match verify::verify(day) {
+            Ok(checks) => checks,
+            Err(err) => {
+                eprintln!("{}", err);
+                wrong += 1;
+                continue;
+            }
+        };
+        for check in checks {
+            println!("{}", check);
+            match check.outcome {
+                Outcome::Correct => correct += 1,
+                Outcome::Skipped => skipped += 1,
+                Outcome::Wrong { .. } | Outcome::Failed(_) => wrong += 1,
+            }
+        }
+    }
+
+    println!(
+        "\n{} correct, {} wrong, {} skipped",
+        correct, wrong, skipped
+    );
+    if wrong == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new(mut args: Vec<String>) -> ExitCode {
+    let mut options = Options::default();
+    if let Some(i) = args.iter().position(|arg| arg == "--parser") {
+        args.remove(i);
+        options.parser = true;
+    }
+    if let Some(i) = args.iter().position(|arg| arg == "--title") {
+        if i + 1 >= args.len() {
+            eprintln!("Missing value for --title\n\n{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+        options.title = args.remove(i + 1);
+        args.remove(i);
+    }
+
+    let day = match args.as_slice() {
+        [day] => match day.parse::<u8>() {
+            Ok(day) if (1..=days::LAST_DAY).contains(&day) => day,
+            _ => {
+                eprintln!("`{}` is not a day between 1 and {}", day, days::LAST_DAY);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match scaffold::create(days::workspace_root(), day, &options) {
+        Ok(dir) => {
+            println!("Created {}", dir.display());
+            println!(
+                "Add it to the `aoc` runner by adding it as dependency in `aoc/Cargo.toml` \
+                 and to `DAYS` in `aoc/src/days.rs`."
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(args: &[String]) -> ExitCode {
+    if args.len() > 1 {
+        eprintln!("{}", USAGE);
+        return ExitCode::FAILURE;
+    }
+    // Any day of the calendar can be fetched once its crate exists, and
+    // `all` only selects the days that have a crate
+    let selected = args.first().map_or("all", String::as_str);
+    let mut days = match selection::parse(selected, days::LAST_DAY) {
+        Ok(days) => days,
+        Err(err) => {
+            eprintln!("Invalid days: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let root = days::workspace_root();
+    if selected == "all" {
+        days.retain(|&day| root.join(days::crate_name(day)).is_dir());
+    }
+    let session = match fetch::session(root) {
+        Ok(session) => session,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut fetcher = Fetcher::new(Ureq::default(), session);
+    let mut success = true;
+    for day in days {
+        for result in [fetcher.input(day), fetcher.example(day)] {
+            match result {
+                Ok(fetched) => println!("{}", fetched),
+                Err(err) => {
+                    eprintln!("Day {:02}: {}", day, err);
+                    success = false;
+                }
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,235 @@
+//! Create the crate of a new day from `day_template`, and register it in the
+//! workspace.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the template crate in the workspace root.
+pub const TEMPLATE_DIR: &str = "day_template";
+
+/// Files copied from the template, relative to the crate root.
+const TEMPLATE_FILES: [&str; 6] = [
+    "example.txt",
+    "answers.txt",
+    "src/lib.rs",
+    "src/main.rs",
+    "src/part1.rs",
+    "src/part2.rs",
+];
+
+const PARSER: &str = "\
+use aoc_core::parse::{lines, IResult};
+use nom::character::complete::not_line_ending;
+
+/// Parse the input, one item per line.
+pub fn items(input: &str) -> IResult<&str, Vec<&str>> {
+    lines(not_line_ending)(input)
+}
+";
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Title of the puzzle.
+    pub title: String,
+    /// Add a nom parser module.
+    pub parser: bool,
+}
+
+/// Create the crate `dayXX` in the workspace at `root`, and add it to the
+/// workspace members. Returns the directory of the new crate.
+pub fn create(root: &Path, day: u8, options: &Options) -> Result<PathBuf, String> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let template = root.join(TEMPLATE_DIR);
+
+    let mut files = Vec::new();
+    for file in TEMPLATE_FILES {
+        let content = fs::read_to_string(template.join(file))
+            .map_err(|err| format!("Could not read template {}: {}", file, err))?;
+        files.push((file.to_string(), instantiate(file, &content, day, options)));
+    }
+    files.push(("Cargo.toml".to_string(), manifest(&name, options)));
+    if options.parser {
+        files.push(("src/parser.rs".to_string(), PARSER.to_string()));
+    }
+
+    // Register first, so nothing is left behind if the workspace manifest is
+    // not as expected
+    let workspace = root.join("Cargo.toml");
+    let members = fs::read_to_string(&workspace)
+        .map_err(|err| format!("Could not read {}: {}", workspace.display(), err))?;
+    let members = register(&members, &name)?;
+
+    for (file, content) in files {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        fs::write(&path, content)
+            .map_err(|err| format!("Could not write {}: {}", path.display(), err))?;
+    }
+    fs::write(&workspace, members)
+        .map_err(|err| format!("Could not write {}: {}", workspace.display(), err))?;
+    Ok(dir)
+}
+
+/// Adapt a template file to the given day.
+fn instantiate(file: &str, content: &str, day: u8, options: &Options) -> String {
+    if !file.ends_with(".rs") {
+        return content.to_string();
+    }
+    let mut content = content
+        .lines()
+        // Instructions for copying the template by hand
+        .filter(|line| !line.trim_start().starts_with("// Change "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    content.push('\n');
+
+    let mut content = content
+        .replace("day01", &format!("day{:02}", day))
+        .replace("Day01", &format!("Day{:02}", day))
+        .replace("const DAY: u8 = 1;", &format!("const DAY: u8 = {};", day))
+        .replace(
+            "const TITLE: &'static str = \"\";",
+            &format!("const TITLE: &'static str = {:?};", options.title),
+        );
+
+    if options.parser {
+        content = match file {
+            "src/lib.rs" => content
+                .replace("pub mod part1;", "pub mod parser;\npub mod part1;")
+                .replace(
+                    "use aoc_core::{Result, Solution};",
+                    "use aoc_core::{parse, Result, Solution};",
+                )
+                .replace(
+                    "type Input<'a> = &'a str;",
+                    "type Input<'a> = Vec<&'a str>;",
+                )
+                .replace("Ok(input)", "parse::all(input, parser::items)"),
+            "src/part1.rs" | "src/part2.rs" => {
+                content.replace("task(_input: &str)", "task(_input: &[&str])")
+            }
+            _ => content,
+        };
+    }
+    content
+}
+
+fn manifest(name: &str, options: &Options) -> String {
+    let mut manifest = format!(
+        "[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core.workspace = true
+",
+        name
+    );
+    if options.parser {
+        manifest.push_str("nom.workspace = true\n");
+    }
+    manifest
+}
+
+/// Add `name` to the `members` of a workspace manifest. A commented out entry
+/// is enabled, otherwise the entry is added after the last day.
+fn register(manifest: &str, name: &str) -> Result<String, String> {
+    let entry = format!("\"{}\",", name);
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members"))
+        .ok_or("No workspace members found in Cargo.toml")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("Unterminated workspace members in Cargo.toml")?;
+
+    let members = &mut lines[start + 1..end];
+    if members.iter().any(|line| line.trim() == entry) {
+        return Err(format!("{} is already a workspace member", name));
+    }
+    if let Some(line) = members
+        .iter_mut()
+        .find(|line| line.trim().trim_start_matches('#').trim() == entry)
+    {
+        *line = format!("  {}", entry);
+    } else {
+        let last_day = members
+            .iter()
+            .rposition(|line| line.trim().starts_with("\"day"))
+            .map_or(end, |i| start + 2 + i);
+        lines.insert(last_day, format!("  {}", entry));
+    }
+
+    let mut manifest = lines.join("\n");
+    manifest.push('\n');
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str =
+        "[workspace]\n\nmembers = [\n  \"aoc\",\n  \"day01\",\n  # \"day03\",\n]\n";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(MANIFEST, "day03").unwrap(),
+            "[workspace]\n\nmembers = [\n  \"aoc\",\n  \"day01\",\n  \"day03\",\n]\n"
+        );
+        assert_eq!(
+            register(MANIFEST, "day02").unwrap(),
+            "[workspace]\n\nmembers = [\n  \"aoc\",\n  \"day01\",\n  \"day02\",\n  # \"day03\",\n]\n"
+        );
+        assert!(register(MANIFEST, "day01").is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let template = root.join(TEMPLATE_DIR);
+        fs::create_dir_all(&root).unwrap();
+        for file in TEMPLATE_FILES {
+            let source = crate::days::workspace_root().join(TEMPLATE_DIR).join(file);
+            fs::create_dir_all(template.join(file).parent().unwrap()).unwrap();
+            fs::copy(source, template.join(file)).unwrap();
+        }
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+
+        let options = Options {
+            title: "Step Counter".to_string(),
+            parser: true,
+        };
+        let dir = create(&root, 21, &options).unwrap();
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day21;"));
+        assert!(lib.contains("const DAY: u8 = 21;"));
+        assert!(lib.contains("\"Step Counter\""));
+        assert!(lib.contains("pub mod parser;"));
+        assert!(!lib.contains("// Change"));
+        let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("use day21::Day21;"));
+        assert!(dir.join("src/parser.rs").exists());
+        assert_eq!(fs::read_to_string(dir.join("example.txt")).unwrap(), "");
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("  \"day21\",\n"));
+        assert!(create(&root, 21, &options).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
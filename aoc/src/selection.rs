@@ -0,0 +1,58 @@
+/// Parse a selection of numbers from the command line, e.g. `5`, `1-20`,
+/// `1,3,5-7` or `all`. Numbers are limited to `1..=max`, and the result is
+/// sorted and free of duplicates.
+pub fn parse(selection: &str, max: u8) -> Result<Vec<u8>, String> {
+    if selection == "all" {
+        return Ok((1..=max).collect());
+    }
+
+    let mut numbers = Vec::new();
+    for item in selection.split(',') {
+        let (start, end) = match item.split_once('-') {
+            Some((start, end)) => (number(start, max)?, number(end, max)?),
+            None => {
+                let n = number(item, max)?;
+                (n, n)
+            }
+        };
+
+        if start > end {
+            return Err(format!("Invalid range `{}`", item));
+        }
+        numbers.extend(start..=end);
+    }
+
+    numbers.sort_unstable();
+    numbers.dedup();
+    Ok(numbers)
+}
+
+fn number(value: &str, max: u8) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(n) if (1..=max).contains(&n) => Ok(n),
+        Ok(n) => Err(format!("`{}` is not between 1 and {}", n, max)),
+        Err(_) => Err(format!("`{}` is not a number", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("all", 3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse("5", 20).unwrap(), vec![5]);
+        assert_eq!(parse("1-4", 20).unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(parse("7,1-2,2", 20).unwrap(), vec![1, 2, 7]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("0", 20).is_err());
+        assert!(parse("21", 20).is_err());
+        assert!(parse("5-3", 20).is_err());
+        assert!(parse("a", 20).is_err());
+        assert!(parse("", 20).is_err());
+    }
+}
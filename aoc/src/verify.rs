@@ -0,0 +1,132 @@
+//! Rerun solutions on the inputs with accepted answers (see [`crate::answers`])
+//! and report every answer that changed.
+
+use std::fmt;
+
+use aoc_core::{DynSolution, Part};
+
+use crate::answers::Answers;
+use crate::days;
+
+/// Outcome of checking one accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        expected: String,
+        actual: String,
+    },
+    /// The solution returned an error instead of an answer.
+    Failed(String),
+    /// The input file does not exist, e.g. an `input.txt` that was not
+    /// downloaded.
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} {} part {}: ", self.day, self.input, self.part)?;
+        match &self.outcome {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Wrong { expected, actual } => {
+                write!(f, "expected {}, got {}", expected, actual)
+            }
+            Outcome::Failed(err) => write!(f, "{}", err.replace('\n', "\n  ")),
+            Outcome::Skipped => write!(f, "skipped, input missing"),
+        }
+    }
+}
+
+/// Check all accepted answers of a day.
+pub fn verify(day: &dyn DynSolution) -> Result<Vec<Check>, String> {
+    let dir = days::workspace_root().join(days::crate_name(day.day()));
+    let answers = Answers::load(&dir).map_err(|err| format!("Day {:02}: {}", day.day(), err))?;
+
+    let mut checks = Vec::new();
+    for entry in &answers.entries {
+        let input = std::fs::read_to_string(dir.join(&entry.input)).ok();
+        for part in Part::ALL {
+            let Some(expected) = entry.answer(part) else {
+                continue;
+            };
+            let outcome = match &input {
+                None => Outcome::Skipped,
+                Some(input) => match day.solve(part, input) {
+                    Ok(actual) if actual == expected => Outcome::Correct,
+                    Ok(actual) => Outcome::Wrong {
+                        expected: expected.to_string(),
+                        actual,
+                    },
+                    Err(err) => Outcome::Failed(err.to_string()),
+                },
+            };
+            checks.push(Check {
+                day: day.day(),
+                input: entry.input.clone(),
+                part,
+                outcome,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let checks = verify(days::day(5).unwrap()).unwrap();
+        assert!(checks.iter().any(|check| check.input == "example.txt"));
+        assert!(checks
+            .iter()
+            .filter(|check| check.input == "example.txt")
+            .all(|check| check.outcome == Outcome::Correct));
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for day in days::DAYS {
+            let dir = days::workspace_root().join(days::crate_name(day.day()));
+            for entry in Answers::load(&dir).unwrap().entries {
+                let Ok(input) = std::fs::read_to_string(dir.join(&entry.input)) else {
+                    continue;
+                };
+                let unix = input.replace("\r\n", "\n");
+                let unix = unix.trim_end();
+                let variants = [
+                    format!("{}\n", unix),
+                    format!("{}\r\n", unix.replace('\n', "\r\n")),
+                    format!("\u{feff}{}", unix),
+                    format!("\u{feff}{}\r\n", unix.replace('\n', "\r\n")),
+                ];
+                for part in Part::ALL
+                    .into_iter()
+                    .filter(|&part| entry.answer(part).is_some())
+                {
+                    let expected = day.solve(part, unix).map_err(|err| err.to_string());
+                    for variant in &variants {
+                        assert_eq!(
+                            day.solve(part, variant).map_err(|err| err.to_string()),
+                            expected,
+                            "Day {:02} {} part {} with input {:?}",
+                            day.day(),
+                            entry.input,
+                            part,
+                            variant
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
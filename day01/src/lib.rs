@@ -1,2 +1,25 @@
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Result, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    /// Lines of the calibration document
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,26 +1,35 @@
-pub fn task(input: &str) -> Option<String> {
-    let res: u32 = input
-        .lines()
-        .filter_map(|line| {
-            let left = line.find(char::is_numeric).unwrap();
-            let right = line.rfind(char::is_numeric).unwrap();
-            format!("{}{}", &line[left..left + 1], &line[right..right + 1])
-                .parse::<u32>()
-                .ok()
+use aoc_core::{Error, Result};
+
+pub fn task(lines: &[&str]) -> Result<String> {
+    let res: u32 = lines
+        .iter()
+        .map(|line| {
+            let left = line.find(|c: char| c.is_ascii_digit());
+            let right = line.rfind(|c: char| c.is_ascii_digit());
+            match (left, right) {
+                (Some(left), Some(right)) => {
+                    format!("{}{}", &line[left..left + 1], &line[right..right + 1])
+                        .parse::<u32>()
+                        .map_err(|_| Error::invalid(format!("no calibration value in {:?}", line)))
+                }
+                _ => Err(Error::invalid(format!("no digit in {:?}", line))),
+            }
         })
-        .sum();
-    Some(res.to_string())
+        .sum::<Result<u32>>()?;
+    Ok(res.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day01;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day01::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "142");
     }
 }
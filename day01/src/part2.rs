@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-pub fn task(input: &str) -> Option<String> {
+use aoc_core::{Error, Result};
+
+pub fn task(lines: &[&str]) -> Result<String> {
     // Map of string digits to their numeric counterparts
     let str_digits: HashMap<&str, &str> = vec![
         ("one", "1"),
@@ -16,10 +18,9 @@ pub fn task(input: &str) -> Option<String> {
     .into_iter()
     .collect();
 
-    let res = input
-        .lines()
-        // Filter out lines that don't contain any digits (should not happen in this case)
-        .filter_map(|line| {
+    let res = lines
+        .iter()
+        .map(|line| {
             // Find first digit appearance from left to right
             let first_str_digit_left = str_digits
                 .keys()
@@ -41,9 +42,7 @@ pub fn task(input: &str) -> Option<String> {
                 }
                 (Some((_, digit_str)), None) => str_digits[digit_str],
                 (None, Some((_, digit))) => digit,
-                _ => {
-                    panic!("No digit found")
-                }
+                _ => return Err(Error::invalid(format!("no digit in {:?}", line))),
             };
 
             // Find first digit appearance from right to left, similar to above
@@ -67,28 +66,30 @@ pub fn task(input: &str) -> Option<String> {
                 }
                 (Some((_, digit_str)), None) => str_digits[digit_str],
                 (None, Some((_, digit))) => digit,
-                _ => {
-                    panic!("No digit found")
-                }
+                _ => return Err(Error::invalid(format!("no digit in {:?}", line))),
             };
 
             // Parse combined digits to u32
-            format!("{}{}", left_digit, right_digit).parse::<u32>().ok()
+            format!("{}{}", left_digit, right_digit)
+                .parse::<u32>()
+                .map_err(|_| Error::invalid(format!("no calibration value in {:?}", line)))
         })
-        .sum::<u32>();
+        .sum::<Result<u32>>()?;
 
-    Some(res.to_string())
+    Ok(res.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day01;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example2.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day01::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "281");
     }
 }
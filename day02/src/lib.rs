@@ -1,3 +1,25 @@
-pub(crate) mod parser;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    type Input<'a> = Vec<parser::Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse::all(input, parser::games)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,8 +1,9 @@
-use nom::bytes::complete::tag;
-use nom::character::complete::{self, alpha0, line_ending, space0};
+use aoc_core::parse::{lines, tag, IResult};
+use nom::branch::alt;
+use nom::character::complete::{self, space0};
+use nom::combinator::{cut, map};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded};
-use nom::IResult;
 
 #[derive(Debug)]
 pub enum Cubes {
@@ -46,14 +47,15 @@ pub struct Game {
 
 fn cube(input: &str) -> IResult<&str, Cubes> {
     let (input, n) = preceded(space0, complete::u32)(input)?;
-    // Parse color and convert to enum
-    let (input, color) = preceded(space0, alpha0)(input).map(|(input, color)| match color {
-        "blue" => (input, Cubes::Blue(n)),
-        "red" => (input, Cubes::Red(n)),
-        "green" => (input, Cubes::Green(n)),
-        _ => panic!("Invalid color"),
-    })?;
-    Ok((input, color))
+    // Parse color and convert to enum, a count must always be followed by a color
+    preceded(
+        space0,
+        cut(alt((
+            map(tag("blue"), move |_| Cubes::Blue(n)),
+            map(tag("red"), move |_| Cubes::Red(n)),
+            map(tag("green"), move |_| Cubes::Green(n)),
+        ))),
+    )(input)
 }
 
 fn set(input: &str) -> IResult<&str, CubeSet> {
@@ -70,5 +72,5 @@ fn game(input: &str) -> IResult<&str, Game> {
 /// Parse a list of games, where each line is a game of the form given in the
 /// problem description
 pub fn games(input: &str) -> IResult<&str, Vec<Game>> {
-    separated_list1(line_ending, game)(input)
+    lines(game)(input)
 }
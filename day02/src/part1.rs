@@ -1,18 +1,15 @@
-pub use parser::{CubeSet, Cubes, Game};
+use aoc_core::Result;
 
-use crate::parser;
+pub use crate::parser::{CubeSet, Cubes, Game};
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, games) = parser::games(input).ok()?;
+pub fn task(games: &[Game]) -> Result<String> {
     // Filter out games that are not valid, then sum the ids of the remaining games
-    Some(
-        games
-            .iter()
-            .filter(|g| valid_game(g))
-            .map(|g| g.id)
-            .sum::<u32>()
-            .to_string(),
-    )
+    Ok(games
+        .iter()
+        .filter(|g| valid_game(g))
+        .map(|g| g.id)
+        .sum::<u32>()
+        .to_string())
 }
 
 fn valid_game(game: &Game) -> bool {
@@ -34,13 +31,21 @@ fn valid_game(game: &Game) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::{Error, Solution};
+
+    use crate::Day02;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day02::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "8");
     }
+
+    #[test]
+    fn test_invalid_color() {
+        let result = Day02::solve_part1("Game 1: 3 blue; 2 purple");
+        assert!(matches!(result, Err(Error::Parse(_))));
+    }
 }
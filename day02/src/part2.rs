@@ -1,8 +1,8 @@
-use crate::parser;
+use aoc_core::Result;
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, games) = parser::games(input).ok()?;
+use crate::parser::Game;
 
+pub fn task(games: &[Game]) -> Result<String> {
     let a = games
         .iter()
         .map(|g| {
@@ -17,18 +17,20 @@ pub fn task(input: &str) -> Option<String> {
         })
         .sum::<u32>();
 
-    Some(a.to_string())
+    Ok(a.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day02;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day02::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "2286");
     }
 }
@@ -1,2 +1,26 @@
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Result, Solution};
+use aoc_grid::Grid;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+    /// The engine schematic, as is
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,67 +1,58 @@
-pub fn task(input: &str) -> Option<String> {
-    // All lines are the same length
-    let line_len = input.lines().next()?.len();
-    let input = input.replace("\r\n", "");
+use aoc_core::{Error, Result};
+use aoc_grid::{Grid, Pos};
 
-    let mut index = 0;
+pub fn task(schematic: &Grid<char>) -> Result<String> {
     let mut result = 0;
-    while let Some(c) = input.chars().nth(index) {
-        if c.is_ascii_digit() {
-            let len = digit_len(&input[index..]);
-            (index..index + len)
-                .any(|i| valid_digit(&input, i, line_len))
-                .then(|| {
-                    result += input[index..index + len].parse::<u32>().unwrap();
-                });
-            index += len;
-        } else {
-            index += 1;
+    for (y, row) in schematic.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let len = digit_len(&row[x..]);
+            if len == 0 {
+                x += 1;
+                continue;
+            }
+            if (x..x + len).any(|x| valid_digit(schematic, (x, y))) {
+                result += part_number(&row[x..x + len])?;
+            }
+            x += len;
         }
     }
 
-    Some(result.to_string())
+    Ok(result.to_string())
 }
 
-fn digit_len(input: &str) -> usize {
-    input
-        .chars()
-        .enumerate()
-        .find(|(_, c)| !c.is_ascii_digit())
-        .expect("Expected correct input")
-        .0
+fn digit_len(row: &[char]) -> usize {
+    row.iter()
+        .position(|c| !c.is_ascii_digit())
+        .unwrap_or(row.len())
 }
 
-fn valid_digit(input: &str, index: usize, line_len: usize) -> bool {
-    // Indices of the surrounding characters
-    let index = index as i32;
-    let line_len = line_len as i32;
-    [
-        index - line_len - 1,
-        index - line_len,
-        index - line_len + 1,
-        index + line_len - 1,
-        index + line_len,
-        index + line_len + 1,
-        index - 1,
-        index + 1,
-    ]
-    .into_iter()
-    // Remove negative indices
-    .filter_map(|i| usize::try_from(i).ok())
-    // Convert to char
-    .filter_map(|i| input.get(i..i + 1).map(|s| s.chars().next().unwrap()))
-    .any(|c| !c.is_ascii_digit() && c != '.')
+fn part_number(digits: &[char]) -> Result<u32> {
+    let digits = digits.iter().collect::<String>();
+    digits
+        .parse()
+        .map_err(|_| Error::invalid(format!("part number {} is too large", digits)))
+}
+
+/// A digit is valid if any of the surrounding characters is a symbol
+fn valid_digit(schematic: &Grid<char>, pos: Pos) -> bool {
+    schematic
+        .neighbours8(pos)
+        .map(|pos| schematic[pos])
+        .any(|c| !c.is_ascii_digit() && c != '.')
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day03;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day03::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "4361");
     }
 }
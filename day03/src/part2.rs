@@ -1,117 +1,90 @@
 use std::collections::HashSet;
+use std::ops::Range;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct StrDigit<'a> {
-    pub digit: &'a str,
-}
+use aoc_core::{Error, Result};
+use aoc_grid::{Grid, Pos};
 
-pub fn task(input: &str) -> Option<String> {
-    let line_len = input.lines().next()?.len() + 2;
-    let mut index = 0;
+pub fn task(schematic: &Grid<char>) -> Result<String> {
     let mut result = 0;
-    while let Some(star_index) = find_star(&input[index..]) {
-        if let Some((left, right)) = gear(input, star_index + index, line_len) {
-            let a = left.digit.parse::<u32>().ok()?;
-            let b = right.digit.parse::<u32>().ok()?;
+    for (pos, _) in schematic.iter().filter(|(_, &c)| c == '*') {
+        if let Some((left, right)) = gear(schematic, pos) {
+            let a = part_number(left)?;
+            let b = part_number(right)?;
             result += a * b;
         }
-        index += star_index + 1;
     }
-    Some(result.to_string())
+    Ok(result.to_string())
 }
 
-fn find_star(input: &str) -> Option<usize> {
-    input
-        .char_indices()
-        .find(|(_, c)| *c == '*')
-        .map(|(i, _)| i)
+fn part_number(digits: &[char]) -> Result<u32> {
+    let digits = digits.iter().collect::<String>();
+    digits
+        .parse()
+        .map_err(|_| Error::invalid(format!("part number {} is too large", digits)))
 }
 
-fn gear(input: &str, star_index: usize, line_len: usize) -> Option<(StrDigit, StrDigit)> {
-    // Indices of the surrounding characters
-    let index = star_index as i32;
-    let line_len = line_len as i32;
-    let str_digits = [
-        index - line_len - 1,
-        index - line_len,
-        index - line_len + 1,
-        index + line_len - 1,
-        index + line_len,
-        index + line_len + 1,
-        index - 1,
-        index + 1,
-    ]
-    .into_iter()
-    // Remove negative indices
-    .filter_map(|i| usize::try_from(i).ok())
-    // Convert indices to digits, if possible
-    .filter_map(|i| str_digit(input, i))
-    // Only want unique digits
-    .collect::<HashSet<StrDigit>>()
-    .into_iter()
-    .collect::<Vec<StrDigit>>();
+fn gear(schematic: &Grid<char>, star: Pos) -> Option<(&[char], &[char])> {
+    let numbers = schematic
+        .neighbours8(star)
+        // Convert positions to the row and columns of whole numbers, if possible
+        .filter_map(|(x, y)| str_digit(schematic.row(y), x).map(|range| (y, range)))
+        // Only want unique numbers
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(|(y, range)| &schematic.row(y)[range])
+        .collect::<Vec<_>>();
 
-    // Exactly two digits should be found
-    if str_digits.len() != 2 {
-        return None;
+    // Exactly two numbers should be found
+    match numbers[..] {
+        [left, right] => Some((left, right)),
+        _ => None,
     }
-
-    Some((str_digits[0], str_digits[1]))
 }
 
-/// Given a string and an index of a digit, return the whole digit
-fn str_digit(input: &str, index: usize) -> Option<StrDigit> {
-    let _ = &input[index..index + 1].parse::<u32>().ok()?;
-
-    // How many characters to the right of the digit are digits?
-    let right = input[index..]
-        .char_indices()
-        .find(|(_, c)| !c.is_ascii_digit());
+/// Given a row and the index of a digit in it, return the columns of the
+/// whole number
+fn str_digit(row: &[char], index: usize) -> Option<Range<usize>> {
+    if !row[index].is_ascii_digit() {
+        return None;
+    }
 
-    // How many characters to the left of the digit are digits?
-    let left = input[..index]
-        .chars()
-        .rev()
-        .enumerate()
-        .find(|(_, c)| !c.is_ascii_digit());
+    // Where the digits to the left and right of the digit end
+    let start = row[..index]
+        .iter()
+        .rposition(|c| !c.is_ascii_digit())
+        .map_or(0, |i| i + 1);
+    let end = row[index..]
+        .iter()
+        .position(|c| !c.is_ascii_digit())
+        .map_or(row.len(), |i| index + i);
 
-    // Return the whole digit
-    match (left, right) {
-        (Some((l_offset, _)), Some((r_offset, _))) => Some(StrDigit {
-            digit: &input[index - l_offset..index + r_offset],
-        }),
-        (Some((l_offset, _)), None) => Some(StrDigit {
-            digit: &input[index - l_offset..],
-        }),
-        (None, Some((r_offset, _))) => Some(StrDigit {
-            digit: &input[..index + r_offset],
-        }),
-        (None, None) => Some(StrDigit { digit: input }),
-    }
+    Some(start..end)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use super::str_digit;
+    use crate::Day03;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day03::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "467835");
     }
 
     #[test]
     fn digit_test() {
         {
-            let input = "abc1353abc";
-            assert_eq!(str_digit(input, 3).unwrap().digit, "1353");
+            let input = "abc1353abc".chars().collect::<Vec<_>>();
+            assert_eq!(str_digit(&input, 3), Some(3..7));
         }
         {
-            let input = "467...";
-            println!("Input: {}", input);
-            assert_eq!(str_digit(input, 2).unwrap().digit, "467");
+            let input = "467...".chars().collect::<Vec<_>>();
+            assert_eq!(str_digit(&input, 2), Some(0..3));
         }
     }
 }
@@ -1,3 +1,25 @@
-pub(crate) mod parser;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+    type Input<'a> = Vec<parser::Card>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse::all(input, parser::cards)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
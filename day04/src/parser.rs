@@ -1,10 +1,9 @@
 use std::collections::HashSet;
 
-use nom::bytes::complete::tag;
-use nom::character::complete::{self, digit1, line_ending, space1};
+use aoc_core::parse::{lines, tag, IResult};
+use nom::character::complete::{self, digit1, space1};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, separated_pair, tuple};
-use nom::IResult;
 
 #[derive(Debug)]
 pub struct Card {
@@ -47,5 +46,5 @@ fn card(input: &str) -> IResult<&str, Card> {
 }
 
 pub fn cards(input: &str) -> IResult<&str, Vec<Card>> {
-    separated_list1(line_ending, card)(input)
+    lines(card)(input)
 }
@@ -1,25 +1,26 @@
-use crate::parser;
+use aoc_core::Result;
 
-pub fn task(_input: &str) -> Option<String> {
-    let (_, cards) = parser::cards(_input).ok()?;
-    Some(
-        cards
-            .iter()
-            .map(|c| c.winning_score())
-            .sum::<u32>()
-            .to_string(),
-    )
+use crate::parser::Card;
+
+pub fn task(cards: &[Card]) -> Result<String> {
+    Ok(cards
+        .iter()
+        .map(|c| c.winning_score())
+        .sum::<u32>()
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day04;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day04::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "13");
     }
 }
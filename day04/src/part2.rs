@@ -1,8 +1,8 @@
-use crate::parser;
+use aoc_core::Result;
 
-pub fn task(_input: &str) -> Option<String> {
-    let (_, cards) = parser::cards(_input).ok()?;
+use crate::parser::Card;
 
+pub fn task(cards: &[Card]) -> Result<String> {
     let mut count: Vec<u32> = vec![1; cards.len()];
 
     cards.iter().enumerate().for_each(|(i, card)| {
@@ -13,17 +13,19 @@ pub fn task(_input: &str) -> Option<String> {
         })
     });
 
-    Some(count.into_iter().sum::<u32>().to_string())
+    Ok(count.into_iter().sum::<u32>().to_string())
 }
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day04;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day04::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "30");
     }
 }
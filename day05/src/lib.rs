@@ -1,3 +1,44 @@
-pub(crate) mod parser;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    type Input<'a> = parser::Almanac<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let almanac = parse::all(input, parser::almanac)?;
+        almanac.validate()?;
+        Ok(almanac)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
+
+/// The value of every seed in every category, one seed per line, e.g.
+/// `seed 79, soil 81, ..., location 82`.
+pub fn trace(input: &str) -> Result<String> {
+    let almanac = Day05::parse(input)?;
+    let mut trace = String::new();
+    for &parser::Seed(seed) in &almanac.seeds {
+        let values: Vec<String> = almanac
+            .trace(seed)
+            .into_iter()
+            .map(|(category, value)| format!("{} {}", category, value))
+            .collect();
+        trace.push_str(&values.join(", "));
+        trace.push('\n');
+    }
+    Ok(trace)
+}
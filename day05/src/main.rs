@@ -1,13 +1,35 @@
-use day05::{part1, part2};
+use std::process::ExitCode;
 
-fn main() {
-    // Output results for both parts
-    println!(
-        "Part 1:\n  {}",
-        part1::task(include_str!("../input.txt")).unwrap_or("No solution found".to_string())
-    );
-    println!(
-        "Part 2:\n  {}",
-        part2::task(include_str!("../input.txt")).unwrap_or("No solution found".to_string())
-    );
+use aoc_core::input;
+use day05::Day05;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "--trace") {
+        return trace(args.get(1).map(String::as_str));
+    }
+    aoc_core::run::<Day05>(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Print the value of every seed in every category, reading the input from
+/// `path` or the default input.
+fn trace(path: Option<&str>) -> ExitCode {
+    let source = input::Source::from_arg(path, input::default_path(env!("CARGO_MANIFEST_DIR")));
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match day05::trace(&input) {
+        Ok(trace) => {
+            print!("{}", trace);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -1,31 +1,192 @@
-use std::ops::Range;
-
+use aoc_core::parse::{lines, tag, IResult};
+use aoc_core::{Error, Result};
+use aoc_interval::{Interval, OffsetMap, OutOfRange};
+use aoc_search::bfs;
 use nom::branch::alt;
-use nom::bytes::complete::tag;
 use nom::character::complete::{self, alpha1, line_ending, space1};
+use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair, terminated, tuple};
-use nom::IResult;
 
 #[derive(Debug)]
 pub struct Seed(pub u64);
 
+/// The seeds to plant, and the maps between categories in the order given by
+/// the almanac.
 #[derive(Debug)]
+pub struct Almanac<'a> {
+    pub seeds: Vec<Seed>,
+    pub maps: Vec<ConversionMap<'a>>,
+}
+
+impl<'a> Almanac<'a> {
+    /// Check that no map has overlapping source ranges, and that every map
+    /// starts at the category the map before it ends at. Ranges that do not
+    /// fit in `u64` are already rejected when parsing.
+    pub fn validate(&self) -> Result<()> {
+        for pair in self.maps.windows(2) {
+            if pair[0].to != pair[1].from {
+                return Err(Error::invalid(format!(
+                    "the {}-to-{} map is followed by a {}-to-{} map",
+                    pair[0].from, pair[0].to, pair[1].from, pair[1].to
+                )));
+            }
+        }
+        for map in &self.maps {
+            // Empty ranges overlap nothing, but would separate ranges that
+            // overlap each other after sorting
+            let mut sources: Vec<_> = map
+                .map
+                .pieces()
+                .iter()
+                .map(|&(source, _)| source)
+                .filter(|source| !source.is_empty())
+                .collect();
+            sources.sort_by_key(|source| source.start);
+            for pair in sources.windows(2) {
+                if pair[0].intersect(&pair[1]).is_some() {
+                    return Err(Error::invalid(format!(
+                        "the {}-to-{} map has overlapping source ranges {:?} and {:?}",
+                        map.from, map.to, pair[0], pair[1]
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The seeds as ranges, for part 2, where the seed values are pairs of
+    /// the first seed and the number of seeds.
+    pub fn seed_ranges(&self) -> Result<Vec<Interval<u64>>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(Error::invalid(format!(
+                "expected pairs of seed values, found {} values",
+                self.seeds.len()
+            )));
+        }
+        self.seeds
+            .chunks_exact(2)
+            .map(|chunk| {
+                let Seed(start) = chunk[0];
+                let Seed(len) = chunk[1];
+                let end = start.checked_add(len).ok_or_else(|| {
+                    Error::invalid(format!("seed range {} {} does not fit in u64", start, len))
+                })?;
+                Ok(Interval::new(start, end))
+            })
+            .collect()
+    }
+
+    /// Value of `seed` in every category, converting with the maps in the
+    /// order of the almanac.
+    pub fn trace(&self, seed: u64) -> Vec<(&'a str, u64)> {
+        let Some(first) = self.maps.first() else {
+            return Vec::new();
+        };
+        let mut value = seed;
+        let mut trace = vec![(first.from, value)];
+        for map in &self.maps {
+            value = map.convert(value);
+            trace.push((map.to, value));
+        }
+        trace
+    }
+
+    /// Maps leading from category `from` to category `to`, found by following
+    /// the names of the maps rather than their order in the almanac.
+    pub fn path(&self, from: &str, to: &str) -> Option<Vec<&ConversionMap<'a>>> {
+        let path = bfs(
+            from,
+            |&category| {
+                self.maps
+                    .iter()
+                    .filter(move |map| map.from == category)
+                    .map(|map| map.to)
+            },
+            |&category| category == to,
+        )?;
+        Some(
+            path.states
+                .windows(2)
+                .map(|pair| {
+                    self.maps
+                        .iter()
+                        .find(|map| map.from == pair[0] && map.to == pair[1])
+                        .expect("Path only uses existing maps")
+                })
+                .collect(),
+        )
+    }
+
+    /// Single map converting category `from` to category `to`. If the maps
+    /// only lead from `to` to `from`, they are inverted.
+    pub fn conversion<'b>(&self, from: &'b str, to: &'b str) -> Result<ConversionMap<'b>>
+    where
+        'a: 'b,
+    {
+        let compose = |path: Vec<&ConversionMap<'a>>, from: &'b str| {
+            let identity = ConversionMap {
+                from,
+                to: from,
+                map: OffsetMap::new(),
+            };
+            path.into_iter()
+                .try_fold(identity, |acc, map| acc.then(map))
+        };
+
+        if let Some(path) = self.path(from, to) {
+            compose(path, from)
+        } else if let Some(path) = self.path(to, from) {
+            compose(path, to)?.inverse().ok_or_else(|| {
+                Error::invalid(format!("map from {} to {} is not invertible", to, from))
+            })
+        } else {
+            Err(Error::invalid(format!(
+                "no maps between {} and {}",
+                from, to
+            )))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ConversionMap<'a> {
     pub from: &'a str,
     pub to: &'a str,
-    /// Values in the range are offset by the given amount
-    pub maps: Vec<(Range<u64>, i128)>,
+    /// Values in a source range are offset to the destination range, other
+    /// values stay the same.
+    pub map: OffsetMap<u64>,
 }
 
-impl ConversionMap<'_> {
+impl<'a> ConversionMap<'a> {
     pub fn convert(&self, value: u64) -> u64 {
-        for (range, offset) in &self.maps {
-            if range.contains(&value) {
-                return (value as i128 + offset) as u64;
-            }
+        self.map.apply(value)
+    }
+
+    /// Map converting with this map first and then with `next`, which must
+    /// start at the category this map ends at.
+    pub fn then(&self, next: &ConversionMap<'a>) -> Result<ConversionMap<'a>> {
+        if self.to != next.from {
+            return Err(Error::invalid(format!(
+                "cannot follow a {}-to-{} map with a {}-to-{} map",
+                self.from, self.to, next.from, next.to
+            )));
         }
-        value
+        Ok(ConversionMap {
+            from: self.from,
+            to: next.to,
+            map: self.map.then(&next.map),
+        })
+    }
+
+    /// Map converting back, if every value is the conversion of exactly one
+    /// value.
+    pub fn inverse(&self) -> Option<ConversionMap<'a>> {
+        Some(ConversionMap {
+            from: self.to,
+            to: self.from,
+            map: self.map.inverse()?,
+        })
     }
 }
 
@@ -34,40 +195,127 @@ fn seeds(input: &str) -> IResult<&str, Vec<Seed>> {
     Ok((input, seeds.into_iter().map(Seed).collect()))
 }
 
-fn range(input: &str) -> IResult<&str, (Range<u64>, i128)> {
-    let (input, (a, _, b, _, r)) =
-        tuple((complete::u64, space1, complete::u64, space1, complete::u64))(input)?;
-    let offset = (a as i128) - (b as i128);
-    Ok((input, ((b..b + r), offset)))
+fn range(input: &str) -> IResult<&str, (Interval<u64>, i128)> {
+    map_res(
+        tuple((complete::u64, space1, complete::u64, space1, complete::u64)),
+        |(a, _, b, _, r)| {
+            let end = b.checked_add(r).ok_or("a source range that fits in u64")?;
+            a.checked_add(r)
+                .ok_or("a destination range that fits in u64")?;
+            Ok::<_, &str>((Interval::new(b, end), (a as i128) - (b as i128)))
+        },
+    )(input)
 }
 
-fn map(input: &str) -> IResult<&str, Vec<(Range<u64>, i128)>> {
-    let (input, ranges) = separated_list1(line_ending, range)(input)?;
-    Ok((input, ranges))
+fn map(input: &str) -> IResult<&str, OffsetMap<u64>> {
+    map_res(lines(range), |ranges| {
+        let mut map = OffsetMap::new();
+        for (source, offset) in ranges {
+            map.push(source, offset)?;
+        }
+        Ok::<_, OutOfRange>(map)
+    })(input)
 }
 
-fn conversion_map(input: &str) -> IResult<&str, ConversionMap> {
+fn conversion_map(input: &str) -> IResult<&str, ConversionMap<'_>> {
     let (input, (from, to)) = terminated(
         separated_pair(alpha1, tag("-to-"), alpha1),
         tuple((tag(" map:"), line_ending)),
     )(input)?;
     let (input, map) = terminated(map, alt((line_ending, tag(""))))(input)?;
-    Ok((
-        input,
-        ConversionMap {
-            from,
-            to,
-            maps: map,
-        },
-    ))
+    Ok((input, ConversionMap { from, to, map }))
 }
 
-fn conversion_maps(input: &str) -> IResult<&str, Vec<ConversionMap>> {
+fn conversion_maps(input: &str) -> IResult<&str, Vec<ConversionMap<'_>>> {
     separated_list1(line_ending, conversion_map)(input)
 }
 
-pub fn almanac(input: &str) -> IResult<&str, (Vec<Seed>, Vec<ConversionMap>)> {
+pub fn almanac(input: &str) -> IResult<&str, Almanac<'_>> {
     let (input, seeds) = terminated(seeds, line_ending)(input)?;
     let (input, maps) = preceded(line_ending, conversion_maps)(input)?;
-    Ok((input, (seeds, maps)))
+    Ok((input, Almanac { seeds, maps }))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::parse;
+    use aoc_interval::RangeSet;
+
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        let input = include_str!("../example.txt");
+        let almanac = parse::all(input, almanac).unwrap();
+        assert_eq!(almanac.path("soil", "light").unwrap().len(), 3);
+        assert!(almanac.path("light", "soil").is_none());
+
+        let seed_to_location = almanac.conversion("seed", "location").unwrap();
+        let location_to_seed = almanac.conversion("location", "seed").unwrap();
+        for seed in 0..110 {
+            let location = almanac.maps.iter().fold(seed, |acc, map| map.convert(acc));
+            assert_eq!(seed_to_location.convert(seed), location);
+            assert_eq!(location_to_seed.convert(location), seed);
+        }
+        assert_eq!(almanac.conversion("seed", "seed").unwrap().convert(42), 42);
+        assert!(almanac.conversion("seed", "weather").is_err());
+
+        // Seeds ending up at a location below 50
+        let seeds = seed_to_location
+            .map
+            .preimage(&RangeSet::from(Interval::new(0, 50)));
+        assert!(seeds.contains(82) && !seeds.contains(79));
+        assert_eq!(seeds.len(), 50);
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = include_str!("../example.txt");
+        let valid = parse::all(input, almanac).unwrap();
+        assert!(valid.validate().is_ok());
+        assert_eq!(valid.seed_ranges().unwrap().len(), 2);
+        assert_eq!(
+            valid.trace(79),
+            [
+                ("seed", 79),
+                ("soil", 81),
+                ("fertilizer", 81),
+                ("water", 81),
+                ("light", 74),
+                ("temperature", 78),
+                ("humidity", 78),
+                ("location", 82)
+            ]
+        );
+
+        let unlinked = input.replace("water-to-light", "soil-to-light");
+        let err = parse::all(&unlinked, almanac).unwrap().validate();
+        assert_eq!(
+            err,
+            Err(Error::invalid(
+                "the fertilizer-to-water map is followed by a soil-to-light map"
+            ))
+        );
+
+        let overlapping = input.replace("52 50 48", "52 50 49");
+        let err = parse::all(&overlapping, almanac).unwrap().validate();
+        assert!(err.unwrap_err().to_string().contains("50..99 and 98..100"));
+
+        let hidden = input.replace("50 98 2\n52 50 48", "50 0 100\n5 10 0\n70 60 10");
+        let err = parse::all(&hidden, almanac).unwrap().validate();
+        assert!(err.unwrap_err().to_string().contains("0..100 and 60..70"));
+
+        let odd = input.replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+        assert!(parse::all(&odd, almanac).unwrap().seed_ranges().is_err());
+
+        let overflow = input.replace("52 50 48", "18446744073709551600 50 48");
+        let Err(Error::Parse(diagnostic)) = parse::all(&overflow, almanac) else {
+            panic!("Expected parse error");
+        };
+        assert_eq!((diagnostic.line, diagnostic.column), (5, 1));
+        assert_eq!(
+            diagnostic.message,
+            "expected a destination range that fits in u64"
+        );
+    }
 }
@@ -1,32 +1,29 @@
-use crate::parser::{self, Seed};
+use aoc_core::{Error, Result};
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, (seeds, maps)) = parser::almanac(input).ok()?;
-    Some(
-        seeds
-            .iter()
-            .map(|Seed(s)| {
-                let mut value = *s;
-                for map in &maps {
-                    value = map.convert(value);
-                }
-                value
-            })
-            .min()
-            .unwrap()
-            .to_string(),
-    )
+use crate::parser::{Almanac, Seed};
+
+pub fn task(almanac: &Almanac) -> Result<String> {
+    let seed_to_location = almanac.conversion("seed", "location")?;
+    Ok(almanac
+        .seeds
+        .iter()
+        .map(|&Seed(seed)| seed_to_location.convert(seed))
+        .min()
+        .ok_or(Error::NoSolution)?
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day05;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day05::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "35");
     }
 }
@@ -1,40 +1,61 @@
-use std::ops::Range;
-
+use aoc_core::{Error, Result};
+use aoc_interval::RangeSet;
 use rayon::prelude::*;
 
-use crate::parser::{self, Seed};
-
-pub fn task(input: &str) -> Option<String> {
-    let (_, (seeds, maps)) = parser::almanac(input).ok()?;
-    let seeds_ranges: Vec<Range<u64>> = seeds
-        .chunks(2)
-        .map(|chunk| {
-            let Seed(start) = chunk[0];
-            let Seed(range) = chunk[1];
-            start..start + range
-        })
-        .collect();
-
-    Some(
-        seeds_ranges
-            .into_par_iter()
-            .flat_map(|range| range)
-            .map(|seed| maps.iter().fold(seed, |acc, map| map.convert(acc)))
-            .min()
-            .unwrap()
-            .to_string(),
-    )
+use crate::parser::Almanac;
+
+pub fn task(almanac: &Almanac) -> Result<String> {
+    Ok(lowest_location(almanac)?.to_string())
+}
+
+/// Map the seed ranges as a whole to locations, splitting them where the
+/// ranges of the maps start and end, and take the lowest location.
+pub fn lowest_location(almanac: &Almanac) -> Result<u64> {
+    let seeds: RangeSet<u64> = almanac.seed_ranges()?.into_iter().collect();
+    almanac
+        .conversion("seed", "location")?
+        .map
+        .apply_set(&seeds)
+        .min()
+        .ok_or(Error::NoSolution)
+}
+
+/// Lowest location found by converting every seed on its own. Far too slow
+/// for the real input, but useful to check [`lowest_location`] against.
+pub fn brute_force(almanac: &Almanac) -> Result<u64> {
+    almanac
+        .seed_ranges()?
+        .into_par_iter()
+        .flat_map(|range| range.range())
+        .map(|seed| almanac.maps.iter().fold(seed, |acc, map| map.convert(acc)))
+        .min()
+        .ok_or(Error::NoSolution)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{parse, Solution};
+
     use super::*;
+    use crate::{parser, Day05};
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day05::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "46");
     }
+
+    #[test]
+    fn test_brute_force() {
+        let input = include_str!("../example.txt");
+        let almanac = parse::all(input, parser::almanac).unwrap();
+        assert_eq!(lowest_location(&almanac), brute_force(&almanac));
+
+        // Seed ranges crossing the boundaries of several map ranges
+        let mut almanac = almanac;
+        almanac.seeds = [0, 30, 45, 60, 90, 20].map(parser::Seed).into();
+        assert_eq!(lowest_location(&almanac), brute_force(&almanac));
+    }
 }
@@ -0,0 +1,214 @@
+use std::ops::RangeInclusive;
+
+use aoc_core::{parse, Error, Result};
+use nom::sequence::pair;
+
+use crate::parser;
+
+/// How far a boat gets in a race, depending on how long its button is held.
+pub trait Boat {
+    /// Distance travelled in a race lasting `time` when holding the button
+    /// for `hold`, with `hold` at most `time`.
+    ///
+    /// Holding longer must first never decrease the distance, and then never
+    /// increase it, with the distance only staying the same around the
+    /// longest distance. This holds if the gain of holding one millisecond
+    /// longer never grows, which is the case for all models here. Distances
+    /// too large for `u64` saturate, which beats any record.
+    fn distance(&self, time: u64, hold: u64) -> u64;
+
+    /// All holds that beat the `record` in a race lasting `time`, or `None` if
+    /// the record cannot be beaten. Found by binary search, so any model can
+    /// be used, without a formula for its distance.
+    fn winning_holds(&self, time: u64, record: u64) -> Option<RangeInclusive<u64>> {
+        let distance = |hold| self.distance(time, hold);
+        // The longest distance is reached at the first hold where holding
+        // longer no longer helps
+        let best = partition_point(0, time, |hold| distance(hold + 1) > distance(hold));
+        if distance(best) <= record {
+            return None;
+        }
+        let first = partition_point(0, best, |hold| distance(hold) <= record);
+        let last = partition_point(best, time, |hold| distance(hold + 1) > record);
+        Some(first..=last)
+    }
+}
+
+/// First value in `low..high` for which `predicate` is false, or `high` if
+/// there is none. The predicate must be true for all values before that.
+fn partition_point(mut low: u64, mut high: u64, mut predicate: impl FnMut(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if predicate(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Every millisecond of holding adds the same speed, which is how the boats
+/// of the puzzle work with an `acceleration` of 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Linear {
+    pub acceleration: u64,
+}
+
+impl Boat for Linear {
+    fn distance(&self, time: u64, hold: u64) -> u64 {
+        self.acceleration
+            .saturating_mul(hold)
+            .saturating_mul(time - hold)
+    }
+}
+
+/// As [`Linear`], but the speed never gets above `top_speed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capped {
+    pub acceleration: u64,
+    pub top_speed: u64,
+}
+
+impl Boat for Capped {
+    fn distance(&self, time: u64, hold: u64) -> u64 {
+        let speed = self.acceleration.saturating_mul(hold).min(self.top_speed);
+        speed.saturating_mul(time - hold)
+    }
+}
+
+/// The first millisecond of holding adds `acceleration` to the speed, and
+/// every following millisecond adds `decay` less, until holding longer adds
+/// nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decaying {
+    pub acceleration: u64,
+    pub decay: u64,
+}
+
+impl Decaying {
+    /// Speed after holding for `hold`, saturating at `u64::MAX`.
+    fn speed(&self, hold: u64) -> u64 {
+        // Milliseconds that still add to the speed
+        let charging = match self.decay {
+            0 => hold,
+            decay => hold.min(self.acceleration.div_ceil(decay)),
+        };
+        // acceleration + (acceleration - decay) + ... over `charging` terms
+        let first = self.acceleration as u128;
+        let last = first - (self.decay as u128) * (charging.saturating_sub(1) as u128);
+        let speed = first.saturating_add(last).saturating_mul(charging as u128) / 2;
+        u64::try_from(speed).unwrap_or(u64::MAX)
+    }
+}
+
+impl Boat for Decaying {
+    fn distance(&self, time: u64, hold: u64) -> u64 {
+        self.speed(hold).saturating_mul(time - hold)
+    }
+}
+
+/// Product of the number of ways to win each race of the puzzle `input`, when
+/// racing with `boat`.
+pub fn margin(input: &str, boat: &impl Boat) -> Result<u64> {
+    let (times, distances) = parse::all(input, pair(parser::times, parser::distances))?;
+    times
+        .into_iter()
+        .zip(distances)
+        .try_fold(1u64, |acc, (time, record)| {
+            let ways = boat
+                .winning_holds(time.into(), record.into())
+                .map_or(0, |holds| holds.end() - holds.start() + 1);
+            acc.checked_mul(ways)
+                .ok_or_else(|| Error::invalid("margin does not fit in 64 bits"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::race;
+
+    /// Winning holds found by trying every hold.
+    fn brute_force(boat: &impl Boat, time: u64, record: u64) -> Option<RangeInclusive<u64>> {
+        let wins: Vec<u64> = (0..=time)
+            .filter(|&hold| boat.distance(time, hold) > record)
+            .collect();
+        Some(*wins.first()?..=*wins.last()?)
+    }
+
+    fn check(boat: &impl Boat) {
+        for time in 0..40 {
+            for record in (0..300).step_by(7) {
+                assert_eq!(
+                    boat.winning_holds(time, record),
+                    brute_force(boat, time, record),
+                    "time {}, record {}",
+                    time,
+                    record
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_models() {
+        check(&Linear { acceleration: 1 });
+        check(&Linear { acceleration: 3 });
+        check(&Linear { acceleration: 0 });
+        check(&Capped {
+            acceleration: 2,
+            top_speed: 9,
+        });
+        check(&Decaying {
+            acceleration: 10,
+            decay: 3,
+        });
+        check(&Decaying {
+            acceleration: 4,
+            decay: 0,
+        });
+        assert_eq!(
+            Decaying {
+                acceleration: 10,
+                decay: 3
+            }
+            .speed(10),
+            10 + 7 + 4 + 1
+        );
+    }
+
+    #[test]
+    fn test_saturating() {
+        // Any hold but none and all of the time goes further than u64::MAX
+        let boat = Decaying {
+            acceleration: u64::MAX,
+            decay: 0,
+        };
+        assert_eq!(boat.speed(u64::MAX / 2), u64::MAX);
+        assert_eq!(boat.distance(u64::MAX, u64::MAX / 2), u64::MAX);
+        assert_eq!(boat.winning_holds(u64::MAX, 0), Some(1..=u64::MAX - 1));
+        assert_eq!(
+            margin(include_str!("../example.txt"), &boat).unwrap(),
+            6 * 14 * 29
+        );
+    }
+
+    #[test]
+    fn test_puzzle_boat() {
+        let boat = Linear { acceleration: 1 };
+        assert_eq!(
+            boat.winning_holds(71530, 940200),
+            race::winning_holds(71530, 940200).unwrap()
+        );
+        assert_eq!(margin(include_str!("../example.txt"), &boat).unwrap(), 288);
+        // Going at most 3 per millisecond, the 200 in 30 milliseconds of the
+        // last race cannot be beaten
+        let capped = Capped {
+            acceleration: 1,
+            top_speed: 3,
+        };
+        assert_eq!(capped.winning_holds(7, 9), Some(2..=3));
+        assert_eq!(margin(include_str!("../example.txt"), &capped).unwrap(), 0);
+    }
+}
@@ -1,3 +1,27 @@
-pub(crate) mod parser;
+pub mod boat;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+pub mod race;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+    type Input<'a> = parser::Races;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse::all(input, parser::races)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
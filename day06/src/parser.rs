@@ -1,9 +1,16 @@
+use aoc_core::parse::{tag, IResult};
 use nom::branch::alt;
-use nom::bytes::complete::tag;
 use nom::character::complete::{self, line_ending, space1};
 use nom::multi::separated_list1;
-use nom::sequence::terminated;
-use nom::IResult;
+use nom::sequence::{pair, terminated};
+
+/// Race times and record distances, where the i:th time belongs to the i:th
+/// distance.
+#[derive(Debug)]
+pub struct Races {
+    pub times: Vec<u32>,
+    pub distances: Vec<u32>,
+}
 
 pub fn times(input: &str) -> IResult<&str, Vec<u32>> {
     let (input, _) = terminated(tag("Time:"), space1)(input)?;
@@ -17,3 +24,8 @@ pub fn distances(input: &str) -> IResult<&str, Vec<u32>> {
         alt((line_ending, tag(""))),
     )(input)
 }
+
+pub fn races(input: &str) -> IResult<&str, Races> {
+    let (input, (times, distances)) = pair(times, distances)(input)?;
+    Ok((input, Races { times, distances }))
+}
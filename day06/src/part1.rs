@@ -1,38 +1,34 @@
-use crate::parser;
+use aoc_core::{Error, Result};
+use aoc_math::Int;
 
-pub fn task(input: &str) -> Option<String> {
-    let (input, times) = parser::times(input).ok()?;
-    let (_, distances) = parser::distances(input).ok()?;
+use crate::parser::Races;
+use crate::race::ways_to_win;
 
-    Some(
-        times
-            .iter()
-            .zip(distances.iter())
-            .map(|(t, d_max)| {
-                // Find min and max time that result equality to d_max. All values
-                // whole values between min and max are valid (second order polynomial)
-                let d_max = *d_max as f32;
-                let t = *t as f32;
-                // Offset a bit to avoid edge cases, i.e. equality. If no offset
-                // is used there might occur extra valid values.
-                let t_min = 0.5f32 * (t - (t * t - 4f32 * d_max).sqrt()) + 0.01;
-                let t_max = 0.5f32 * (t + (t * t - 4f32 * d_max).sqrt()) - 0.01;
-                t_max.floor() as u32 - t_min.ceil() as u32 + 1
-            })
-            .product::<u32>()
-            .to_string(),
-    )
+pub fn task(races: &Races) -> Result<String> {
+    Ok(races
+        .times
+        .iter()
+        .zip(races.distances.iter())
+        .try_fold(1u64, |acc, (&time, &record)| -> Result<u64> {
+            let ways = ways_to_win(u64::from(time), u64::from(record))
+                .map_err(|_| Error::invalid("race does not fit in 64 bits"))?;
+            acc.mul(ways)
+                .map_err(|_| Error::invalid("margin does not fit in 64 bits"))
+        })?
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day06;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day06::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "288");
     }
 }
@@ -1,41 +1,39 @@
-use crate::parser;
+use aoc_core::{Error, Result};
 
-pub fn task(input: &str) -> Option<String> {
-    let (input, times) = parser::times(input).ok()?;
-    let (_, distances) = parser::distances(input).ok()?;
+use crate::parser::Races;
+use crate::race::ways_to_win;
 
-    let time = times
+pub fn task(races: &Races) -> Result<String> {
+    let time = races
+        .times
         .iter()
         .fold(String::new(), |acc, n| acc + &n.to_string())
-        .parse::<u64>()
-        .expect("Could not parse time");
+        .parse::<u128>()
+        .map_err(|_| Error::invalid("time does not fit in 128 bits"))?;
 
-    let distance = distances
+    let distance = races
+        .distances
         .iter()
         .fold(String::new(), |acc, n| acc + &n.to_string())
-        .parse::<u64>()
-        .expect("Could not parse distance");
+        .parse::<u128>()
+        .map_err(|_| Error::invalid("distance does not fit in 128 bits"))?;
 
-    // Find min and max time that result equality to d_max. All values
-    // whole values between min and max are valid.(second order polynomial)
-    let d_max = distance as f32;
-    let t = time as f32;
-    // Offset a bit to avoid edge cases, i.e. equality. If no offset is used, the
-    // there might occur extra valid values.
-    let t_min = 0.5f32 * (t - (t * t - 4f32 * d_max).sqrt()) + 0.01;
-    let t_max = 0.5f32 * (t + (t * t - 4f32 * d_max).sqrt()) - 0.01;
-    Some((t_max.floor() as u32 - t_min.ceil() as u32 + 1).to_string())
+    let ways =
+        ways_to_win(time, distance).map_err(|_| Error::invalid("race does not fit in 128 bits"))?;
+    Ok(ways.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day06;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day06::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "71503");
     }
 }
@@ -0,0 +1,113 @@
+use std::ops::RangeInclusive;
+
+use aoc_math::{isqrt, Int, Overflow};
+
+/// Whether holding the button for `hold` at the start of a race lasting
+/// `time` beats the `record`. A distance too large for the type beats any
+/// record that fits in it.
+fn beats<T: Int>(time: T, hold: T, record: T) -> Result<bool, Overflow> {
+    match hold.mul(time.sub(hold)?) {
+        Ok(distance) => Ok(distance > record),
+        Err(Overflow) => Ok(true),
+    }
+}
+
+/// All whole numbers of milliseconds to hold the button for that beat the
+/// `record` in a race lasting `time`, or `None` if the record cannot be
+/// beaten.
+pub fn winning_holds<T: Int>(time: T, record: T) -> Result<Option<RangeInclusive<T>>, Overflow> {
+    // The distance hold * (time - hold) is symmetric around time / 2, where
+    // it is the largest
+    let half = time.div(T::TWO)?;
+    if !beats(time, half, record)? {
+        return Ok(None);
+    }
+
+    // hold * (time - hold) > record holds strictly between the roots of
+    // hold² - time * hold + record, at (time ± √(time² - 4 * record)) / 2
+    let discriminant = time
+        .mul(time)
+        .and_then(|square| square.sub(T::TWO.mul(T::TWO)?.mul(record)?));
+    let first = match discriminant {
+        Ok(discriminant) => {
+            // The integer root is at most one off from the lowest winning
+            // hold, which is at most half the time
+            let root = isqrt(discriminant).expect("Unsigned numbers have a root");
+            let mut hold = time.sub(root)?.div(T::TWO)?;
+            while !beats(time, hold, record)? {
+                hold = hold.add(T::ONE)?;
+            }
+            while hold > T::ZERO && beats(time, hold.sub(T::ONE)?, record)? {
+                hold = hold.sub(T::ONE)?;
+            }
+            hold
+        }
+        // time² does not fit in the type, so find the lowest winning hold
+        // by bisection instead, knowing that half the time wins
+        Err(Overflow) => {
+            let (mut low, mut high) = (T::ZERO, half);
+            while low < high {
+                let mid = low.add(high.sub(low)?.div(T::TWO)?)?;
+                if beats(time, mid, record)? {
+                    high = mid;
+                } else {
+                    low = mid.add(T::ONE)?;
+                }
+            }
+            low
+        }
+    };
+    Ok(Some(first..=time.sub(first)?))
+}
+
+/// Number of ways to hold the button for a whole number of milliseconds that
+/// beat the `record` in a race lasting `time`.
+pub fn ways_to_win<T: Int>(time: T, record: T) -> Result<T, Overflow> {
+    match winning_holds(time, record)? {
+        Some(holds) => holds.end().sub(*holds.start())?.add(T::ONE),
+        None => Ok(T::ZERO),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winning_holds() {
+        assert_eq!(winning_holds(7u64, 9), Ok(Some(2..=5)));
+        assert_eq!(winning_holds(30u64, 200), Ok(Some(11..=19)));
+        assert_eq!(winning_holds(4u64, 4), Ok(None));
+        assert_eq!(winning_holds(0u64, 0), Ok(None));
+        assert_eq!(winning_holds(1u64, 0), Ok(None));
+        assert_eq!(winning_holds(2u64, 0), Ok(Some(1..=1)));
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        assert_eq!(ways_to_win(7u64, 9), Ok(4));
+        assert_eq!(ways_to_win(15u64, 40), Ok(8));
+        assert_eq!(ways_to_win(30u64, 200), Ok(9));
+        assert_eq!(ways_to_win(5u64, 100), Ok(0));
+        assert_eq!(ways_to_win(71530u128, 940200), Ok(71503));
+    }
+
+    #[test]
+    fn test_large() {
+        // time² overflows, so the bounds are found by bisection
+        for (time, record) in [
+            (u64::MAX, u64::MAX - 1),
+            (10_000_000_000, 24_999_999_999_999_999),
+        ] {
+            let holds = winning_holds(time, record).unwrap().unwrap();
+            let (first, last) = (*holds.start(), *holds.end());
+            assert!(beats(time, first, record).unwrap());
+            assert!(!beats(time, first - 1, record).unwrap());
+            assert!(!beats(time, last + 1, record).unwrap());
+            assert_eq!(
+                ways_to_win(time as u128, record as u128),
+                Ok((last - first + 1) as u128)
+            );
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use aoc_core::Result;
+
+use crate::parser::Hand;
+use crate::ranking::{ranked, HandType, Key, Rules};
+
+/// What decided the rank of a hand against the hand ranked just below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// There is no weaker hand.
+    Weakest,
+    /// The hand has a better type.
+    HandType,
+    /// The hands have the same type, and the card at this position, starting
+    /// at 0, is the first that is stronger.
+    Card(usize),
+    /// The hands are equally strong.
+    Tie,
+}
+
+/// How a hand was ranked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub hand: Hand<'a>,
+    /// Rank of the hand, starting at 1 for the weakest hand.
+    pub rank: usize,
+    pub key: Key,
+    /// The hand with every wildcard replaced by the card it plays as.
+    pub played: String,
+    pub decision: Decision,
+}
+
+impl Explanation<'_> {
+    pub fn hand_type(&self) -> Option<HandType> {
+        self.key.hand_type()
+    }
+}
+
+/// Sizes of groups of equal cards, in decreasing order.
+fn groups(counts: &HashMap<char, u8>) -> Vec<u8> {
+    let mut groups: Vec<u8> = counts.values().copied().filter(|&n| n > 0).collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    groups
+}
+
+fn plain_counts(rules: &Rules, hand: &str) -> HashMap<char, u8> {
+    let mut counts = HashMap::new();
+    for card in hand.chars().filter(|&card| !rules.is_wildcard(card)) {
+        *counts.entry(card).or_default() += 1;
+    }
+    counts
+}
+
+/// `hand` with every wildcard replaced by the card that gives the best type,
+/// which is the card of the largest group, the strongest one if several
+/// groups are the largest. If all cards are wildcards they become the
+/// strongest card.
+pub fn substitute(rules: &Rules, hand: &str) -> Result<String> {
+    rules.key(hand)?;
+    let counts = plain_counts(rules, hand);
+    let best = counts
+        .iter()
+        .max_by_key(|&(&card, &count)| (count, rules.strength(card)))
+        .map(|(&card, _)| card)
+        .or_else(|| rules.plain_cards().last().copied());
+    Ok(hand
+        .chars()
+        .map(|card| match best {
+            Some(best) if rules.is_wildcard(card) => best,
+            _ => card,
+        })
+        .collect())
+}
+
+/// Best groups of `hand` found by trying every way to replace its wildcards,
+/// to check the greedy rule of [`Rules::key`] against. As the groups do not
+/// depend on the positions of the cards, it is enough to try every multiset
+/// of replacements.
+pub fn optimal_groups(rules: &Rules, hand: &str) -> Result<Vec<u8>> {
+    fn search(
+        cards: &[char],
+        wildcards: usize,
+        counts: &mut HashMap<char, u8>,
+        best: &mut Vec<u8>,
+    ) {
+        let Some((&card, rest)) = cards.split_first() else {
+            if wildcards == 0 {
+                *best = groups(counts).max(std::mem::take(best));
+            }
+            return;
+        };
+        // Replace `n` of the remaining wildcards by `card`
+        for n in 0..=wildcards {
+            *counts.entry(card).or_default() += n as u8;
+            search(rest, wildcards - n, counts, best);
+            *counts.entry(card).or_default() -= n as u8;
+        }
+    }
+
+    rules.key(hand)?;
+    let wildcards = hand.chars().filter(|&card| rules.is_wildcard(card)).count();
+    let mut counts = plain_counts(rules, hand);
+    let cards = rules.plain_cards();
+    if cards.is_empty() {
+        // Every card is a wildcard, so they all act as the same card, as in
+        // `Rules::key`
+        return Ok(vec![wildcards as u8]);
+    }
+    let mut best = Vec::new();
+    search(&cards, wildcards, &mut counts, &mut best);
+    Ok(best)
+}
+
+/// Explanations of how every hand is ranked, from the weakest to the
+/// strongest hand. Equally strong hands keep their order.
+pub fn explain<'a>(rules: &Rules, hands: &[Hand<'a>]) -> Result<Vec<Explanation<'a>>> {
+    let ranked = ranked(rules, hands)?;
+    let mut explanations: Vec<Explanation> = Vec::with_capacity(ranked.len());
+    for (i, (key, hand)) in ranked.into_iter().enumerate() {
+        let decision = match explanations.last() {
+            None => Decision::Weakest,
+            Some(below) if below.key.groups != key.groups => Decision::HandType,
+            Some(below) => below
+                .key
+                .strengths
+                .iter()
+                .zip(&key.strengths)
+                .position(|(a, b)| a != b)
+                .map_or(Decision::Tie, Decision::Card),
+        };
+        explanations.push(Explanation {
+            hand,
+            rank: i + 1,
+            played: substitute(rules, hand.cards)?,
+            key,
+            decision,
+        });
+    }
+    Ok(explanations)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::parse;
+
+    use super::*;
+    use crate::parser;
+    use crate::ranking::winnings;
+
+    #[test]
+    fn test_explain() {
+        let hands = parse::all(include_str!("../example.txt"), parser::hands).unwrap();
+        let explanations = explain(&Rules::jokers(), &hands).unwrap();
+        let summary: Vec<_> = explanations
+            .iter()
+            .map(|e| {
+                (
+                    e.hand.cards,
+                    e.played.as_str(),
+                    e.hand_type().unwrap(),
+                    e.decision,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("32T3K", "32T3K", HandType::OnePair, Decision::Weakest),
+                ("KK677", "KK677", HandType::TwoPairs, Decision::HandType),
+                ("T55J5", "T5555", HandType::FourOfAKind, Decision::HandType),
+                ("QQQJA", "QQQQA", HandType::FourOfAKind, Decision::Card(0)),
+                ("KTJJT", "KTTTT", HandType::FourOfAKind, Decision::Card(0)),
+            ]
+        );
+        assert_eq!(explanations[4].rank, 5);
+        assert_eq!(explanations[4].hand.bid, 220);
+
+        let hands =
+            [("KK677", 1), ("KTJJT", 2), ("KK677", 3)].map(|(cards, bid)| Hand { cards, bid });
+        let explanations = explain(&Rules::standard(), &hands).unwrap();
+        assert_eq!(explanations[1].decision, Decision::Card(1));
+        assert_eq!(explanations[2].decision, Decision::Tie);
+        // Tied hands are ranked as they are paid
+        let paid: u64 = explanations
+            .iter()
+            .map(|e| e.rank as u64 * e.hand.bid)
+            .sum();
+        assert_eq!(paid, 13);
+        assert_eq!(winnings(&Rules::standard(), &hands).unwrap(), paid);
+    }
+
+    #[test]
+    fn test_substitute() {
+        let rules = Rules::jokers();
+        assert_eq!(substitute(&rules, "JJJJJ").unwrap(), "AAAAA");
+        // Two pairs, the jokers join the stronger one
+        assert_eq!(substitute(&rules, "22J33").unwrap(), "22333");
+        assert!(substitute(&rules, "22J3").is_err());
+    }
+
+    #[test]
+    fn test_greedy_is_optimal() {
+        // Every hand made of a few cards including jokers
+        let rules = Rules::jokers();
+        let cards = ['J', '2', '3', '4', 'A'];
+        for i in 0..cards.len().pow(5) {
+            let hand: String = (0..5)
+                .map(|position| cards[i / cards.len().pow(position) % cards.len()])
+                .collect();
+            let key = rules.key(&hand).unwrap();
+            assert_eq!(
+                key.groups,
+                optimal_groups(&rules, &hand).unwrap(),
+                "{}",
+                hand
+            );
+
+            let played = substitute(&rules, &hand).unwrap();
+            assert_eq!(Rules::standard().key(&played).unwrap().groups, key.groups);
+        }
+        assert_eq!(optimal_groups(&rules, "JJJJJ").unwrap(), [5]);
+
+        // Rules where every card is a wildcard
+        let rules = Rules::new("JK", "JK", 5).unwrap();
+        for hand in ["JJJJJ", "JJKKK"] {
+            assert_eq!(optimal_groups(&rules, hand).unwrap(), [5]);
+            assert_eq!(rules.key(hand).unwrap().groups, [5]);
+        }
+    }
+}
@@ -1,2 +1,27 @@
+pub mod explain;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+pub mod ranking;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+    type Input<'a> = Vec<parser::Hand<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse::all(input, parser::hands)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_core::parse::{lines, IResult};
+use nom::character::complete::{self, one_of, space1};
+use nom::combinator::recognize;
+use nom::multi::count;
+use nom::sequence::separated_pair;
+
+/// All cards, from weakest to strongest in part 1.
+pub const CARDS: &str = "23456789TJQKA";
+pub const HAND_SIZE: usize = 5;
+
+/// A hand of cards and the bid placed on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hand<'a> {
+    /// The cards in the order they were dealt, e.g. `32T3K`.
+    pub cards: &'a str,
+    pub bid: u64,
+}
+
+fn cards(input: &str) -> IResult<&str, &str> {
+    recognize(count(one_of(CARDS), HAND_SIZE))(input)
+}
+
+fn hand(input: &str) -> IResult<&str, Hand<'_>> {
+    let (input, (cards, bid)) = separated_pair(cards, space1, complete::u64)(input)?;
+    Ok((input, Hand { cards, bid }))
+}
+
+/// Parse one hand and its bid per line.
+pub fn hands(input: &str) -> IResult<&str, Vec<Hand<'_>>> {
+    lines(hand)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{parse, Error};
+
+    use super::*;
+
+    #[test]
+    fn test_hands() {
+        assert_eq!(
+            parse::all("32T3K 765\nKK677 28\n", hands).unwrap(),
+            [
+                Hand {
+                    cards: "32T3K",
+                    bid: 765
+                },
+                Hand {
+                    cards: "KK677",
+                    bid: 28
+                }
+            ]
+        );
+
+        for (input, position) in [
+            ("32T3K 765\nKK6X7 28", (2, 4)),
+            ("32T3K 765\nKK67 28", (2, 5)),
+            ("32T3KA 765", (1, 6)),
+            ("32T3K -765", (1, 7)),
+            ("32T3K", (1, 6)),
+        ] {
+            let Err(Error::Parse(diagnostic)) = parse::all(input, hands) else {
+                panic!("Expected parse error for {:?}", input);
+            };
+            assert_eq!(
+                (diagnostic.line, diagnostic.column),
+                position,
+                "{:?}",
+                input
+            );
+        }
+    }
+}
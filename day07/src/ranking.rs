@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use aoc_core::{Error, Result};
+
+use crate::parser::{Hand, CARDS, HAND_SIZE};
+
+/// The card that is a joker in part 2.
+const JOKER: char = 'J';
+
+/// Types of hands of five cards, ordered from worst to best hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Type of a hand of five cards, from the sizes of its groups of equal
+    /// cards in decreasing order.
+    pub fn from_groups(groups: &[u8]) -> Option<Self> {
+        let hand_type = match groups {
+            [5] => HandType::FiveOfAKind,
+            [4, 1] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, 1, 1] => HandType::ThreeOfAKind,
+            [2, 2, 1] => HandType::TwoPairs,
+            [2, 1, 1, 1] => HandType::OnePair,
+            [1, 1, 1, 1, 1] => HandType::HighCard,
+            _ => return None,
+        };
+        Some(hand_type)
+    }
+}
+
+/// Precomputed key of a hand. Comparing keys compares the hands by the rules
+/// they were made with.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    /// Sizes of the groups of equal cards in decreasing order, after playing
+    /// the wildcards. Comparing these compares the hand types, e.g. a full
+    /// house `[3, 2]` beats three of a kind `[3, 1, 1]`.
+    pub groups: Vec<u8>,
+    /// Strength of every card, in the order of the hand, to break ties.
+    pub strengths: Vec<u8>,
+}
+
+impl Key {
+    /// Type of the hand, for hands of five cards.
+    pub fn hand_type(&self) -> Option<HandType> {
+        HandType::from_groups(&self.groups)
+    }
+}
+
+/// Rules of a game of Camel Cards.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    /// Strength of every card, from 0 for the weakest card.
+    strengths: HashMap<char, u8>,
+    /// Cards that act like whatever card makes the hand strongest.
+    wildcards: Vec<char>,
+    hand_size: usize,
+}
+
+impl Rules {
+    /// Rules with the cards in `order` from weakest to strongest, where the
+    /// `wildcards` are any of the cards in `order`.
+    pub fn new(order: &str, wildcards: &str, hand_size: usize) -> Result<Self> {
+        let mut strengths = HashMap::new();
+        for (strength, card) in order.chars().enumerate() {
+            let strength = u8::try_from(strength).map_err(|_| Error::invalid("too many cards"))?;
+            if strengths.insert(card, strength).is_some() {
+                return Err(Error::invalid(format!("card {} is ordered twice", card)));
+            }
+        }
+        if let Some(card) = wildcards.chars().find(|card| !strengths.contains_key(card)) {
+            return Err(Error::invalid(format!("wildcard {} is not a card", card)));
+        }
+        if hand_size == 0 || hand_size > u8::MAX as usize {
+            return Err(Error::invalid(format!("invalid hand size {}", hand_size)));
+        }
+        Ok(Rules {
+            strengths,
+            wildcards: wildcards.chars().collect(),
+            hand_size,
+        })
+    }
+
+    /// The rules of part 1.
+    pub fn standard() -> Self {
+        Rules::new(CARDS, "", HAND_SIZE).expect("Valid rules")
+    }
+
+    /// The rules of part 2, where `J` is a joker and the weakest card.
+    pub fn jokers() -> Self {
+        let order: String = std::iter::once(JOKER)
+            .chain(CARDS.chars().filter(|&card| card != JOKER))
+            .collect();
+        Rules::new(&order, &JOKER.to_string(), HAND_SIZE).expect("Valid rules")
+    }
+
+    pub fn is_wildcard(&self, card: char) -> bool {
+        self.wildcards.contains(&card)
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn strength(&self, card: char) -> Option<u8> {
+        self.strengths.get(&card).copied()
+    }
+
+    /// Cards that are not wildcards, from weakest to strongest.
+    pub fn plain_cards(&self) -> Vec<char> {
+        let mut cards: Vec<char> = self
+            .strengths
+            .keys()
+            .copied()
+            .filter(|&card| !self.is_wildcard(card))
+            .collect();
+        cards.sort_by_key(|card| self.strengths[card]);
+        cards
+    }
+
+    /// Key for comparing `hand` with other hands.
+    pub fn key(&self, hand: &str) -> Result<Key> {
+        let mut strengths = Vec::with_capacity(self.hand_size);
+        let mut counts: HashMap<char, u8> = HashMap::new();
+        let mut wildcards = 0;
+        for card in hand.chars() {
+            let strength = self
+                .strengths
+                .get(&card)
+                .ok_or_else(|| Error::invalid(format!("invalid card {} in hand {}", card, hand)))?;
+            strengths.push(*strength);
+            if self.is_wildcard(card) {
+                wildcards += 1;
+            } else {
+                *counts.entry(card).or_default() += 1;
+            }
+        }
+        if strengths.len() != self.hand_size {
+            return Err(Error::invalid(format!(
+                "hand {} does not have {} cards",
+                hand, self.hand_size
+            )));
+        }
+
+        let mut groups: Vec<u8> = counts.into_values().collect();
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        // Wildcards make the largest group larger, or are a group of their own
+        // if all cards are wildcards
+        match groups.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => groups.push(wildcards),
+        }
+        Ok(Key { groups, strengths })
+    }
+}
+
+/// The `hands` with their keys, from the weakest to the strongest hand.
+/// Equally strong hands keep their order, so that every hand has one rank.
+pub fn ranked<'a>(rules: &Rules, hands: &[Hand<'a>]) -> Result<Vec<(Key, Hand<'a>)>> {
+    let mut keyed = hands
+        .iter()
+        .map(|&hand| Ok((rules.key(hand.cards)?, hand)))
+        .collect::<Result<Vec<_>>>()?;
+    keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(keyed)
+}
+
+/// Total winnings of the `hands`, where every bid is multiplied by the rank of
+/// its hand, starting at 1 for the weakest hand.
+pub fn winnings(rules: &Rules, hands: &[Hand]) -> Result<u64> {
+    ranked(rules, hands)?
+        .into_iter()
+        .zip(1u64..)
+        .try_fold(0u64, |total, ((_, Hand { bid, .. }), rank)| {
+            rank.checked_mul(bid)
+                .and_then(|winnings| total.checked_add(winnings))
+        })
+        .ok_or_else(|| Error::invalid("total winnings do not fit in 64 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_type() {
+        let rules = Rules::standard();
+        let hand_type = |hand| rules.key(hand).unwrap().hand_type().unwrap();
+        assert_eq!(hand_type("32T3K"), HandType::OnePair);
+        assert_eq!(hand_type("KTJJT"), HandType::TwoPairs);
+        assert_eq!(hand_type("AAAAA"), HandType::FiveOfAKind);
+        assert_eq!(hand_type("23456"), HandType::HighCard);
+
+        let rules = Rules::jokers();
+        let hand_type = |hand| rules.key(hand).unwrap().hand_type().unwrap();
+        assert_eq!(hand_type("KTJJT"), HandType::FourOfAKind);
+        assert_eq!(hand_type("JJJJJ"), HandType::FiveOfAKind);
+        assert_eq!(hand_type("2345J"), HandType::OnePair);
+        assert_eq!(hand_type("22J33"), HandType::FullHouse);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let rules = Rules::standard();
+        assert!(rules.key("33332").unwrap() > rules.key("2AAAA").unwrap());
+        assert!(rules.key("KK677").unwrap() > rules.key("KTJJT").unwrap());
+
+        // Jokers are the weakest card when breaking ties
+        let rules = Rules::jokers();
+        assert!(rules.key("QQQQ2").unwrap() > rules.key("JKKK2").unwrap());
+    }
+
+    #[test]
+    fn test_configuration() {
+        // Three card hands with aces low and two wildcards
+        let rules = Rules::new("A23456789TJQK", "2K", 3).unwrap();
+        assert_eq!(rules.key("A2K").unwrap().groups, [3]);
+        assert_eq!(rules.key("A34").unwrap().groups, [1, 1, 1]);
+        assert!(rules.key("A345").is_err());
+        assert!(rules.key("A3X").is_err());
+
+        // The joker rules keep the order of the other cards
+        let plain: String = Rules::jokers().plain_cards().into_iter().collect();
+        assert_eq!(plain, CARDS.replace(JOKER, ""));
+
+        assert!(Rules::new("AA", "", 5).is_err());
+        assert!(Rules::new("AK", "J", 5).is_err());
+        assert!(Rules::new("AK", "", 0).is_err());
+    }
+
+    #[test]
+    fn test_winnings() {
+        let hands = [
+            Hand {
+                cards: "AAAAA",
+                bid: u64::MAX / 2 + 1,
+            },
+            Hand {
+                cards: "22222",
+                bid: 1,
+            },
+        ];
+        assert_eq!(winnings(&Rules::standard(), &hands[1..]).unwrap(), 1);
+        assert!(winnings(&Rules::standard(), &hands).is_err());
+    }
+}
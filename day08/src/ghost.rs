@@ -0,0 +1,178 @@
+use aoc_core::{find_cycle, Error, Result};
+use aoc_math::crt;
+
+use crate::parser::Network;
+
+/// Steps at which a ghost walking the network is on a node ending with `Z`.
+///
+/// A ghost is at a node and an instruction, so it repeats its walk as soon
+/// as it is at the same node and instruction again. After `start` steps, the
+/// walk repeats every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghost {
+    /// Steps before `start` at which the ghost is at an end, in order.
+    pub tail: Vec<u64>,
+    pub start: u64,
+    pub period: u64,
+    /// Steps in `start..start + period` at which the ghost is at an end, in
+    /// order. The ghost is also at an end any multiple of `period` later.
+    pub offsets: Vec<u64>,
+}
+
+impl Ghost {
+    /// Walk `network` from the node `from` until the walk repeats.
+    pub fn walk(network: &Network, from: &str) -> Result<Self> {
+        if !network.nodes.contains_key(from) {
+            return Err(Error::invalid(format!("unknown node {}", from)));
+        }
+        let instructions = network.instructions.0.len();
+        let cycle = find_cycle((from, 0), |&(node, index)| {
+            (network.next(node, index), (index + 1) % instructions)
+        });
+
+        let (start, period) = (cycle.start as u64, cycle.period as u64);
+        let (tail, offsets) = (0..start + period)
+            .filter(|&step| cycle.state_after(step as usize).0.ends_with('Z'))
+            .partition(|&step| step < start);
+        Ok(Ghost {
+            tail,
+            start,
+            period,
+            offsets,
+        })
+    }
+
+    /// Whether the ghost is at an end after `step` steps.
+    pub fn at_end(&self, step: u64) -> bool {
+        if step < self.start {
+            self.tail.binary_search(&step).is_ok()
+        } else {
+            let offset = self.start + (step - self.start) % self.period;
+            self.offsets.binary_search(&offset).is_ok()
+        }
+    }
+}
+
+/// First step at which all `ghosts` are at an end at the same time, or `None`
+/// if they never are.
+pub fn first_common_step(ghosts: &[Ghost]) -> Result<Option<u64>> {
+    // Before every ghost is in its cycle, try every step
+    let cycling = ghosts.iter().map(|ghost| ghost.start).max().unwrap_or(0);
+    if let Some(step) = (0..cycling).find(|&step| ghosts.iter().all(|ghost| ghost.at_end(step))) {
+        return Ok(Some(step));
+    }
+
+    // From then on, every ghost must be at one of its offsets, so combine the
+    // congruences of every choice of offsets, one ghost at a time
+    let mut solutions = vec![(0i128, 1i128)];
+    for ghost in ghosts {
+        let mut combined = Vec::new();
+        for &solution in &solutions {
+            for &offset in &ghost.offsets {
+                let found = crt([solution, (offset as i128, ghost.period as i128)])
+                    .map_err(|_| Error::invalid("step count does not fit in 128 bits"))?;
+                if let Some(found) = found {
+                    combined.push(found);
+                }
+            }
+        }
+        combined.sort_unstable();
+        combined.dedup();
+        solutions = combined;
+    }
+
+    // Lowest step of every solution that is not before all ghosts cycle
+    let cycling = cycling as i128;
+    solutions
+        .into_iter()
+        .map(|(step, modulus)| {
+            let behind = (cycling - step).max(0);
+            step + (behind + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .map(|step| {
+            u64::try_from(step).map_err(|_| Error::invalid("step count does not fit in 64 bits"))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::parse;
+
+    use super::*;
+    use crate::parser;
+
+    /// The walks of the ghosts starting at nodes ending with `A`, sorted by
+    /// the name of their start.
+    fn walk_all(network: &Network) -> Vec<Ghost> {
+        let mut starts: Vec<&str> = network
+            .nodes
+            .keys()
+            .copied()
+            .filter(|name| name.ends_with('A'))
+            .collect();
+        starts.sort_unstable();
+        starts
+            .into_iter()
+            .map(|start| Ghost::walk(network, start).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_walk() {
+        let network = parse::all(include_str!("../example3.txt"), parser::map).unwrap();
+        let ghosts = walk_all(&network);
+        assert_eq!(
+            ghosts[0],
+            Ghost {
+                tail: vec![],
+                start: 1,
+                period: 2,
+                offsets: vec![2],
+            }
+        );
+        assert_eq!((ghosts[1].start, ghosts[1].period), (1, 6));
+        assert_eq!(ghosts[1].offsets, [3, 6]);
+        assert_eq!(first_common_step(&ghosts).unwrap(), Some(6));
+    }
+
+    #[test]
+    fn test_unaligned() {
+        // 11A is at an end at step 1 and every third step from 3, and AAA at
+        // every even step from 2, so taking the first ends would give 2
+        let input = "L
+
+AAA = (BBB, BBB)
+BBB = (CCZ, CCZ)
+CCZ = (DDD, DDD)
+DDD = (CCZ, CCZ)
+11A = (11Z, 11Z)
+11Z = (12B, 12B)
+12B = (13Z, 13Z)
+13Z = (14B, 14B)
+14B = (12B, 12B)
+";
+        let network = parse::all(input, parser::map).unwrap();
+        let ghosts = walk_all(&network);
+        assert_eq!(ghosts[0].tail, [1]);
+        let common = first_common_step(&ghosts).unwrap();
+        assert_eq!(common, Some(6));
+        let brute_force = (0..100).find(|&step| ghosts.iter().all(|ghost| ghost.at_end(step)));
+        assert_eq!(common, brute_force);
+
+        // 21A is only at an end at odd steps
+        let input = "L
+
+AAA = (BBB, BBB)
+BBB = (CCZ, CCZ)
+CCZ = (DDD, DDD)
+DDD = (CCZ, CCZ)
+21A = (21Z, 21Z)
+21Z = (22B, 22B)
+22B = (21Z, 21Z)
+";
+        let network = parse::all(input, parser::map).unwrap();
+        assert_eq!(first_common_step(&walk_all(&network)).unwrap(), None);
+    }
+}
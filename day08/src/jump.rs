@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use aoc_core::{Error, Result};
+
+use crate::parser::Network;
+
+/// Precomputed jumps over whole passes through the instructions, to follow a
+/// huge number of instructions in logarithmic time.
+#[derive(Debug, Clone)]
+pub struct JumpTable<'a> {
+    names: Vec<&'a str>,
+    index: HashMap<&'a str, usize>,
+    /// Left and right node of every node.
+    links: Vec<[usize; 2]>,
+    /// Every instruction, 0 for left and 1 for right.
+    instructions: Vec<usize>,
+    /// Node reached from every node after 2^k passes, for every k.
+    passes: Vec<Vec<usize>>,
+}
+
+impl<'a> JumpTable<'a> {
+    /// Jumps of a validated `network`.
+    pub fn new(network: &Network<'a>) -> Self {
+        let names: Vec<&str> = network.nodes.keys().copied().collect();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, &name)| (name, i))
+            .collect();
+        let links = names
+            .iter()
+            .map(|name| {
+                let node = &network.nodes[name];
+                [index[node.left], index[node.right]]
+            })
+            .collect();
+        let instructions = network
+            .instructions
+            .0
+            .bytes()
+            .map(|instruction| usize::from(instruction == b'R'))
+            .collect();
+
+        let mut table = JumpTable {
+            names,
+            index,
+            links,
+            instructions,
+            passes: Vec::new(),
+        };
+        let pass = (0..table.names.len())
+            .map(|node| table.walk(node, table.instructions.len()))
+            .collect();
+        table.passes.push(pass);
+        // Any number of steps that fits in u64 is at most 2^64 passes
+        for _ in 1..u64::BITS {
+            let last = &table.passes[table.passes.len() - 1];
+            let next = last.iter().map(|&node| last[node]).collect();
+            table.passes.push(next);
+        }
+        table
+    }
+
+    fn node(&self, name: &str) -> Result<usize> {
+        self.index
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::invalid(format!("unknown node {}", name)))
+    }
+
+    /// Node reached after the first `steps` instructions from `node`.
+    fn walk(&self, node: usize, steps: usize) -> usize {
+        self.instructions[..steps]
+            .iter()
+            .fold(node, |node, &instruction| self.links[node][instruction])
+    }
+
+    /// Node reached from `from` after following `steps` instructions.
+    pub fn after(&self, from: &str, steps: u64) -> Result<&'a str> {
+        let length = self.instructions.len() as u64;
+        let (passes, rest) = (steps / length, steps % length);
+        let node = (0..u64::BITS as usize)
+            .filter(|&k| passes >> k & 1 == 1)
+            .fold(self.node(from)?, |node, k| self.passes[k][node]);
+        Ok(self.names[self.walk(node, rest as usize)])
+    }
+
+    /// Precompute the first steps at which nodes matching `predicate` are
+    /// reached.
+    pub fn search(&self, predicate: impl Fn(&str) -> bool) -> Search<'_, 'a> {
+        let first: Vec<Option<usize>> = (0..self.names.len())
+            .map(|start| {
+                let mut node = start;
+                for (step, &instruction) in self.instructions.iter().enumerate() {
+                    if predicate(self.names[node]) {
+                        return Some(step);
+                    }
+                    node = self.links[node][instruction];
+                }
+                None
+            })
+            .collect();
+
+        // The nodes at the start of every pass repeat within as many passes
+        // as there are nodes, so a match is found within that many passes
+        // or never
+        let levels = (usize::BITS - self.names.len().leading_zeros()) as usize;
+        let mut found = vec![first.iter().map(Option::is_some).collect::<Vec<_>>()];
+        for k in 1..levels {
+            let last = &found[k - 1];
+            let next = (0..self.names.len())
+                .map(|node| last[node] || last[self.passes[k - 1][node]])
+                .collect();
+            found.push(next);
+        }
+        Search {
+            table: self,
+            first,
+            found,
+        }
+    }
+}
+
+/// First steps at which nodes matching a predicate are reached, see
+/// [`JumpTable::search`].
+#[derive(Debug, Clone)]
+pub struct Search<'t, 'a> {
+    table: &'t JumpTable<'a>,
+    /// First step of a pass from every node at which a match is reached.
+    first: Vec<Option<usize>>,
+    /// Whether a match is reached within 2^k passes from every node, for
+    /// every k.
+    found: Vec<Vec<bool>>,
+}
+
+impl Search<'_, '_> {
+    /// First step at which a matching node is reached from `from`, starting
+    /// at 0 if `from` matches, or `None` if no matching node is ever reached.
+    pub fn first(&self, from: &str) -> Result<Option<u64>> {
+        let table = self.table;
+        let mut node = table.node(from)?;
+        // Skip the most passes without a match
+        let mut passes = 0u64;
+        for k in (0..self.found.len()).rev() {
+            if !self.found[k][node] {
+                node = table.passes[k][node];
+                passes += 1 << k;
+            }
+        }
+        Ok(self.first[node].map(|step| passes * table.instructions.len() as u64 + step as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::parse;
+
+    use super::*;
+    use crate::parser;
+
+    fn network(input: &str) -> Network<'_> {
+        parse::all(input, parser::map).unwrap()
+    }
+
+    #[test]
+    fn test_after() {
+        let network = network(include_str!("../example2.txt"));
+        let table = JumpTable::new(&network);
+        // Stepping one by one
+        let mut node = "AAA";
+        for steps in 0..20 {
+            assert_eq!(table.after("AAA", steps).unwrap(), node, "steps {}", steps);
+            node = network.next(node, steps as usize % 3);
+        }
+        assert_eq!(table.after("AAA", u64::MAX).unwrap(), "ZZZ");
+        assert!(table.after("XYZ", 1).is_err());
+
+        // The ghosts of part 2 keep moving between their nodes
+        let network = self::network(include_str!("../example3.txt"));
+        let table = JumpTable::new(&network);
+        assert_eq!(table.after("22A", 1_000_000_000_000).unwrap(), "22B");
+        assert_eq!(table.after("22A", 1_000_000_000_002).unwrap(), "22Z");
+    }
+
+    #[test]
+    fn test_search() {
+        let network = network(include_str!("../example2.txt"));
+        let table = JumpTable::new(&network);
+        let search = table.search(|node| node == "ZZZ");
+        assert_eq!(search.first("AAA").unwrap(), Some(6));
+        assert_eq!(search.first("ZZZ").unwrap(), Some(0));
+        assert_eq!(
+            table.search(|node| node == "BBB").first("ZZZ").unwrap(),
+            None
+        );
+
+        let network = self::network(include_str!("../example3.txt"));
+        let table = JumpTable::new(&network);
+        let search = table.search(|node| node == "22Z");
+        assert_eq!(search.first("22A").unwrap(), Some(3));
+        assert_eq!(search.first("11A").unwrap(), None);
+    }
+}
@@ -1,3 +1,29 @@
-pub(crate) mod parser;
+pub mod ghost;
+pub mod jump;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+    type Input<'a> = parser::Network<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let network = parse::all(input, parser::map)?;
+        network.validate()?;
+        Ok(network)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
-use nom::bytes::complete::{tag, take_while1};
+use aoc_core::parse::{lines, tag, IResult};
+use aoc_core::{Error, Result};
+use nom::bytes::complete::take_while1;
 use nom::character::complete::line_ending;
-use nom::multi::{many1, separated_list1};
+use nom::multi::many1;
 use nom::sequence::{delimited, separated_pair, terminated};
-use nom::IResult;
 
 /// A str slice of direction, e.g. "RLRRL".
 #[derive(Debug)]
@@ -17,7 +18,42 @@ pub struct Node<'a> {
     pub right: &'a str,
 }
 
-fn instructions(input: &str) -> IResult<&str, Instructions> {
+/// The nodes of the network, by name, and the instructions for navigating it.
+#[derive(Debug)]
+pub struct Network<'a> {
+    pub nodes: HashMap<&'a str, Node<'a>>,
+    pub instructions: Instructions<'a>,
+}
+
+impl<'a> Network<'a> {
+    /// Check that every node leads to nodes of the network.
+    pub fn validate(&self) -> Result<()> {
+        for node in self.nodes.values() {
+            for next in [node.left, node.right] {
+                if !self.nodes.contains_key(next) {
+                    return Err(Error::invalid(format!(
+                        "node {} leads to unknown node {}",
+                        node.start, next
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Node reached from `node` by following the instruction at `index`,
+    /// where `node` is in a validated network.
+    pub fn next(&self, node: &str, index: usize) -> &'a str {
+        let node = &self.nodes[node];
+        match self.instructions.0.as_bytes()[index] {
+            b'L' => node.left,
+            b'R' => node.right,
+            _ => unreachable!("instructions only contain L and R"),
+        }
+    }
+}
+
+fn instructions(input: &str) -> IResult<&str, Instructions<'_>> {
     let (input, instr) =
         terminated(take_while1(|c| c == 'L' || c == 'R'), many1(line_ending))(input)?;
     Ok((input, Instructions(instr)))
@@ -27,7 +63,7 @@ fn node_identifier(input: &str) -> IResult<&str, &str> {
     take_while1(|c: char| c.is_ascii_uppercase() || c.is_alphanumeric())(input)
 }
 
-fn node(input: &str) -> IResult<&str, (&str, Node)> {
+fn node(input: &str) -> IResult<&str, (&str, Node<'_>)> {
     let (input, start) = terminated(node_identifier, tag(" = "))(input)?;
     let (input, (left, right)) = delimited(
         tag("("),
@@ -37,13 +73,19 @@ fn node(input: &str) -> IResult<&str, (&str, Node)> {
     Ok((input, (start, Node { start, left, right })))
 }
 
-fn nodes(input: &str) -> IResult<&str, HashMap<&str, Node>> {
-    let (input, nodes) = separated_list1(line_ending, node)(input)?;
+fn nodes(input: &str) -> IResult<&str, HashMap<&str, Node<'_>>> {
+    let (input, nodes) = lines(node)(input)?;
     Ok((input, nodes.into_iter().collect()))
 }
 
-pub fn map(input: &str) -> IResult<&str, (HashMap<&str, Node>, Instructions)> {
+pub fn map(input: &str) -> IResult<&str, Network<'_>> {
     let (input, instructions) = instructions(input)?;
     let (input, nodes) = nodes(input)?;
-    Ok((input, (nodes, instructions)))
+    Ok((
+        input,
+        Network {
+            nodes,
+            instructions,
+        },
+    ))
 }
@@ -1,43 +1,36 @@
-use crate::parser;
+use aoc_core::{Error, Result};
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, (nodes, instructions)) = parser::map(input).ok()?;
+use crate::jump::JumpTable;
+use crate::parser::Network;
 
-    // Start at the first node (from instructions)
-    let mut current = "AAA";
-    for (count, instr) in instructions.0.chars().cycle().enumerate() {
-        // End is always `ZZZ`
-        if current == "ZZZ" {
-            return Some(count.to_string());
-        }
-
-        // Next node, based on instruction.
-        current = match instr {
-            'L' => nodes.get(current)?.left,
-            'R' => nodes.get(current)?.right,
-            _ => panic!("invalid instruction"),
-        }
+pub fn task(network: &Network) -> Result<String> {
+    // Start at the first node (from instructions), the end is always `ZZZ`
+    let table = JumpTable::new(network);
+    match table.search(|node| node == "ZZZ").first("AAA")? {
+        Some(count) => Ok(count.to_string()),
+        None => Err(Error::NoSolution),
     }
-    None
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day08;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day08::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "2");
     }
 
     #[test]
     fn test_task2() {
         let input = include_str!("../example2.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day08::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "6");
     }
 }
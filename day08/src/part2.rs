@@ -1,71 +1,43 @@
-use crate::parser;
+use aoc_core::{Error, Result};
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, (nodes, instructions)) = parser::map(input).ok()?;
+use crate::ghost::{first_common_step, Ghost};
+use crate::parser::Network;
 
-    let starting_nodes = nodes
+pub fn task(network: &Network) -> Result<String> {
+    let starting_nodes = network
+        .nodes
         .keys()
         .copied()
         .filter(|name| name.ends_with('A'))
         .collect::<Vec<&str>>();
-
-    // All starting node have a specific cycle length, where end of the cycle is
-    // always a valid end node.
-    let cycle_lengths: Vec<usize> = starting_nodes
-        .iter()
-        .map(|&start| {
-            let mut current = start;
-            instructions
-                .0
-                .chars()
-                .cycle()
-                .enumerate()
-                .find_map(|(count, instr)| {
-                    current = match instr {
-                        'L' => nodes.get(current)?.left,
-                        'R' => nodes.get(current)?.right,
-                        _ => panic!("invalid instruction"),
-                    };
-
-                    if current.ends_with('Z') {
-                        Some(count + 1)
-                    } else {
-                        None
-                    }
-                })
-                .expect("no cycle found")
-        })
-        .collect();
-
-    // Need to find the least common multiple of all cycle lengths.
-    Some(lcm(&cycle_lengths).to_string())
-}
-
-fn lcm(numbers: &[usize]) -> usize {
-    if numbers.len() == 1 {
-        return numbers[0];
+    if starting_nodes.is_empty() {
+        return Err(Error::invalid("no starting node ending with A"));
     }
-    let a = numbers[0];
-    let b = lcm(&numbers[1..]);
-    a * b / gcd_of_two_numbers(a, b)
-}
 
-fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
-    if b == 0 {
-        return a;
+    // Every ghost is at an end at some steps before its walk repeats, and at
+    // some offsets into every repeat after that
+    let ghosts = starting_nodes
+        .into_iter()
+        .map(|start| Ghost::walk(network, start))
+        .collect::<Result<Vec<_>>>()?;
+    match first_common_step(&ghosts)? {
+        Some(step) => Ok(step.to_string()),
+        // The ghosts are never all on nodes ending with Z at the same step
+        None => Err(Error::NoSolution),
     }
-    gcd_of_two_numbers(b, a % b)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day08;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example3.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day08::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "6");
     }
 }
@@ -1,2 +1,35 @@
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Error, Result, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+    /// History of each value in the report
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|num| {
+                        num.parse()
+                            .map_err(|_| Error::parse_at(input, num, "expected a number"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,29 +1,20 @@
-pub fn task(input: &str) -> Option<String> {
-    let values: Vec<Vec<i32>> = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .filter_map(|num| num.parse().ok())
-                .collect()
-        })
-        .collect();
+use aoc_core::{Error, Result};
 
-    Some(
-        values
-            .iter()
-            .map(|row| next_prediction(row))
-            .sum::<i32>()
-            .to_string(),
-    )
+pub fn task(values: &[Vec<i32>]) -> Result<String> {
+    Ok(values
+        .iter()
+        .map(|row| next_prediction(row))
+        .sum::<Result<i32>>()?
+        .to_string())
 }
 
-fn next_prediction(row: &[i32]) -> i32 {
+fn next_prediction(row: &[i32]) -> Result<i32> {
     let mut differences = vec![];
     let mut current = row.to_vec();
     let last = current
         .last()
         .copied()
-        .expect("Expected at least one value");
+        .ok_or_else(|| Error::invalid("empty history"))?;
 
     // Keep looping until all values are 0
     loop {
@@ -37,21 +28,23 @@ fn next_prediction(row: &[i32]) -> i32 {
             current
                 .last()
                 .copied()
-                .expect("Expected at least one value"),
+                .ok_or_else(|| Error::invalid("differences of history never become all zeros"))?,
         );
     }
-    last + differences.into_iter().sum::<i32>()
+    Ok(last + differences.into_iter().sum::<i32>())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day09;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day09::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "114");
     }
 }
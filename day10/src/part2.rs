@@ -1,16 +1,21 @@
-pub fn task(_input: &str) -> Option<String> {
-    None
+use aoc_core::{Error, Result};
+use aoc_grid::Grid;
+
+pub fn task(_sketch: &Grid<char>) -> Result<String> {
+    Err(Error::NoSolution)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day10;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day10::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "");
     }
 }
@@ -1,2 +1,25 @@
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Result, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    type Input<'a> = parser::Image;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parser::image(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_core::Result;
+use aoc_grid::Grid;
+
+/// Image of the observatory, with the (x, y) position of every galaxy.
+#[derive(Debug)]
+pub struct Image {
+    pub galaxies: Vec<(i32, i32)>,
+    /// Rows without any galaxy
+    pub empty_rows: Vec<i32>,
+    /// Columns without any galaxy
+    pub empty_columns: Vec<i32>,
+}
+
+pub fn image(input: &str) -> Result<Image> {
+    let space = Grid::parse(input, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("expected '#' or '.'"),
+    })?;
+
+    let galaxies = space
+        .iter()
+        .filter(|(_, &galaxy)| galaxy)
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .collect();
+    let empty_rows = space
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| !row.contains(&true))
+        .map(|(y, _)| y as i32)
+        .collect();
+    let empty_columns = space
+        .columns()
+        .enumerate()
+        .filter_map(|(x, mut column)| (!column.any(|&galaxy| galaxy)).then_some(x as i32))
+        .collect();
+
+    Ok(Image {
+        galaxies,
+        empty_rows,
+        empty_columns,
+    })
+}
@@ -1,74 +1,67 @@
+use aoc_core::Result;
 use itertools::Itertools;
 
-pub fn task(input: &str, increase: u64) -> Option<String> {
-    let r = input.lines().count() as i32;
-    let c = input.lines().next().unwrap().chars().count() as i32;
+use crate::parser::Image;
 
-    let mut galaxies = Vec::new();
+/// Expansion factor from the problem description
+const INCREASE: u64 = 1_000_000;
 
-    input.lines().enumerate().for_each(|(y, line)| {
-        line.char_indices().for_each(|(x, c)| match c {
-            '#' => {
-                galaxies.push((x as i32, y as i32));
-            }
-            '.' => {}
-            _ => panic!("Unknown character: {}", c),
-        })
-    });
-
-    let empty_rows: Vec<i32> = (0..r)
-        .filter(|y| !galaxies.iter().any(|(_, gy)| *gy == *y))
-        .collect();
+pub fn task(image: &Image) -> Result<String> {
+    Ok(sum_of_distances(image, INCREASE).to_string())
+}
 
-    let empty_columns: Vec<i32> = (0..c)
-        .filter(|x| !galaxies.iter().any(|(gx, _)| *gx == *x))
-        .collect();
+/// Sum of the distances between all pairs of galaxies, when every empty row
+/// and column is replaced by `increase` empty rows or columns.
+pub fn sum_of_distances(image: &Image, increase: u64) -> u64 {
+    let Image {
+        galaxies,
+        empty_rows,
+        empty_columns,
+    } = image;
 
-    Some(
-        galaxies
-            .iter()
-            .combinations(2)
-            // .par_bridge()
-            .map(|g_vec| (g_vec[0], g_vec[1]))
-            .map(|(&(fx, fy), &(tx, ty))| {
-                // L-distance is optimal for this problem
-                let d = (fx - tx).abs() + (fy - ty).abs();
-                let row_range = fy.min(ty)..fy.max(ty);
-                let col_range = fx.min(tx)..fx.max(tx);
-                let empty_rows_between = empty_rows
-                    .iter()
-                    .filter(|&ey| row_range.contains(ey))
-                    .count() as u64;
+    galaxies
+        .iter()
+        .combinations(2)
+        // .par_bridge()
+        .map(|g_vec| (g_vec[0], g_vec[1]))
+        .map(|(&(fx, fy), &(tx, ty))| {
+            // L-distance is optimal for this problem
+            let d = (fx - tx).abs() + (fy - ty).abs();
+            let row_range = fy.min(ty)..fy.max(ty);
+            let col_range = fx.min(tx)..fx.max(tx);
+            let empty_rows_between = empty_rows
+                .iter()
+                .filter(|&ey| row_range.contains(ey))
+                .count() as u64;
 
-                let empty_cols_between = empty_columns
-                    .iter()
-                    .filter(|&ex| col_range.contains(ex))
-                    .count() as u64;
+            let empty_cols_between = empty_columns
+                .iter()
+                .filter(|&ex| col_range.contains(ex))
+                .count() as u64;
 
-                d as u64 + empty_rows_between * (increase - 1) + empty_cols_between * (increase - 1)
-            })
-            .sum::<u64>()
-            .to_string(),
-    )
+            d as u64 + empty_rows_between * (increase - 1) + empty_cols_between * (increase - 1)
+        })
+        .sum::<u64>()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::Solution;
+
     use super::*;
+    use crate::Day11;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input, 10);
-        assert!(result.is_some());
-        assert_eq!(result.unwrap(), "1030");
+        let image = Day11::parse(input).unwrap();
+        assert_eq!(sum_of_distances(&image, 10), 1030);
     }
 
     #[test]
     fn test_task2() {
         let input = include_str!("../example.txt");
-        let result = task(input, 100);
-        assert!(result.is_some());
-        assert_eq!(result.unwrap(), "8410");
+        let image = Day11::parse(input).unwrap();
+        assert_eq!(sum_of_distances(&image, 100), 8410);
     }
 }
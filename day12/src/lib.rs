@@ -1,2 +1,25 @@
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Result, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+    type Input<'a> = Vec<parser::Record<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parser::records(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,12 +1,7 @@
-use day12::{part1, part2};
-fn main() {
-    // Output results for both parts
-    println!(
-        "Part 1:\n  {}",
-        part1::task(include_str!("../input.txt")).unwrap_or("No solution found".to_string())
-    );
-    println!(
-        "Part 2:\n  {}",
-        part2::task(include_str!("../input.txt")).unwrap_or("No solution found".to_string())
-    );
+use std::process::ExitCode;
+
+use day12::Day12;
+
+fn main() -> ExitCode {
+    aoc_core::run::<Day12>(env!("CARGO_MANIFEST_DIR"))
 }
@@ -0,0 +1,35 @@
+use aoc_core::{Error, Result};
+
+/// Condition record of a row of springs, i.e. the springs (`.`, `#` or `?`)
+/// and the sizes of the contiguous groups of damaged springs.
+#[derive(Debug)]
+pub struct Record<'a> {
+    pub springs: &'a str,
+    pub groups: Vec<usize>,
+}
+
+pub fn records(input: &str) -> Result<Vec<Record<'_>>> {
+    input
+        .lines()
+        .map(|row| {
+            let (springs, groups) = row
+                .split_once(' ')
+                .ok_or_else(|| Error::parse_at(input, row, "expected springs and groups"))?;
+            if let Some(i) = springs.find(|c| !matches!(c, '.' | '#' | '?')) {
+                return Err(Error::parse_at(
+                    input,
+                    &springs[i..],
+                    "expected one of '.', '#' or '?'",
+                ));
+            }
+            let groups = groups
+                .split(',')
+                .map(|s| {
+                    s.parse()
+                        .map_err(|_| Error::parse_at(input, s, "expected a group size"))
+                })
+                .collect::<Result<_>>()?;
+            Ok(Record { springs, groups })
+        })
+        .collect()
+}
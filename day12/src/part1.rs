@@ -1,29 +1,23 @@
+use aoc_core::Result;
 use itertools::Itertools;
 use rayon::prelude::*;
 
-pub fn task(input: &str) -> Option<String> {
-    Some(
-        input
-            .lines()
-            .filter_map(|row| {
-                let (springs, groups) = row.split_once(' ')?;
-                let unknown_positions = springs
-                    .char_indices()
-                    .filter_map(|(i, c)| (c == '?').then_some(i))
-                    .collect::<Vec<usize>>();
+use crate::parser::Record;
 
-                let required_groups: Vec<usize> =
-                    groups.split(',').filter_map(|s| s.parse().ok()).collect();
+pub fn task(records: &[Record]) -> Result<String> {
+    Ok(records
+        .iter()
+        .map(|record| {
+            let unknown_positions = record
+                .springs
+                .char_indices()
+                .filter_map(|(i, c)| (c == '?').then_some(i))
+                .collect::<Vec<usize>>();
 
-                Some(possible_positions(
-                    &unknown_positions,
-                    &required_groups,
-                    springs,
-                ))
-            })
-            .sum::<usize>()
-            .to_string(),
-    )
+            possible_positions(&unknown_positions, &record.groups, record.springs)
+        })
+        .sum::<usize>()
+        .to_string())
 }
 
 /// Get number of valid spring positions by filling in the unknown positions and
@@ -65,13 +59,15 @@ fn groups_from_placement(placement: &[usize], springs: &str) -> Vec<usize> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day12;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day12::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "21");
     }
 }
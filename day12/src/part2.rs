@@ -1,28 +1,27 @@
+use aoc_core::Result;
 use itertools::Itertools;
 
-pub fn task(input: &str) -> Option<String> {
-    Some(
-        input
-            .lines()
-            .filter_map(|row| {
-                let (springs, groups) = row.split_once(' ')?;
-
-                let springs = std::iter::once(springs).cycle().take(5).join("?");
-
-                let required_groups: Vec<usize> =
-                    groups.split(',').filter_map(|s| s.parse().ok()).collect();
-                let n = required_groups.len();
-                let required_groups = required_groups
-                    .into_iter()
-                    .cycle()
-                    .take(5 * n)
-                    .collect_vec();
-
-                Some(possible_positions(&springs, required_groups))
-            })
-            .sum::<usize>()
-            .to_string(),
-    )
+use crate::parser::Record;
+
+pub fn task(records: &[Record]) -> Result<String> {
+    Ok(records
+        .iter()
+        .map(|record| {
+            let springs = std::iter::once(record.springs).cycle().take(5).join("?");
+
+            let n = record.groups.len();
+            let required_groups = record
+                .groups
+                .iter()
+                .copied()
+                .cycle()
+                .take(5 * n)
+                .collect_vec();
+
+            possible_positions(&springs, required_groups)
+        })
+        .sum::<usize>()
+        .to_string())
 }
 
 // Function from https://github.com/mfornet/advent-of-code-2023/blob/main/src/bin/12.rs#L6
@@ -65,13 +64,15 @@ fn possible_positions(springs: &str, required_groups: Vec<usize>) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day12;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day12::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "525152");
     }
 }
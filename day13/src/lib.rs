@@ -1,3 +1,25 @@
-pub(crate) mod parser;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+    type Input<'a> = Vec<parser::Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse::all(input, parser::patterns)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
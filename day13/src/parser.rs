@@ -1,8 +1,7 @@
+use aoc_core::parse::{blocks, line, lines, IResult};
+use aoc_grid::Grid;
 use itertools::Itertools;
-use nom::bytes::complete::take_till;
-use nom::character::complete::line_ending;
-use nom::multi::separated_list1;
-use nom::IResult;
+use nom::combinator::map_res;
 
 /// A reflection is a horizontal or vertical reflection in a pattern. It is
 /// defined by the indices of the two rows or columns that are each other's
@@ -16,31 +15,24 @@ pub enum Reflection {
 /// A pattern is a rectangular grid of characters.
 #[derive(Debug)]
 pub struct Pattern {
-    rows: Vec<String>,
-    columns: Vec<String>,
+    grid: Grid<char>,
+    /// The grid with its rows and columns swapped, so vertical reflections can
+    /// be found as horizontal ones
+    transposed: Grid<char>,
 }
 
 impl Pattern {
-    /// Create a new pattern from a list of rows.
-    pub fn from_rows(rows: Vec<String>) -> Self {
-        let width = rows[0].len();
-
-        let mut columns = vec![String::new(); width];
-        for row in &rows {
-            for (i, c) in row.chars().enumerate() {
-                columns[i].push(c);
-            }
-        }
-
-        Self { rows, columns }
+    pub fn new(grid: Grid<char>) -> Self {
+        let transposed = grid.transpose();
+        Self { grid, transposed }
     }
 
     /// Find a horizontal or vertical reflection in the pattern.
     pub fn find_reflection(&self) -> Option<Reflection> {
-        if let Some(i) = Self::find_reflection_in(&self.rows) {
+        if let Some(i) = Self::find_reflection_in(&self.grid) {
             return Some(Reflection::Horizontal((), i + 1));
         }
-        if let Some(i) = Self::find_reflection_in(&self.columns) {
+        if let Some(i) = Self::find_reflection_in(&self.transposed) {
             return Some(Reflection::Vertical((), i + 1));
         }
         None
@@ -50,19 +42,20 @@ impl Pattern {
     /// max_changes changes in the pattern. Note that this will require at least
     /// one change, i.e. won't find exact reflections.
     pub fn find_almost_reflection(&self, max_changes: usize) -> Option<Reflection> {
-        if let Some(i) = Self::find_potential_reflection_in(&self.rows, max_changes) {
+        if let Some(i) = Self::find_potential_reflection_in(&self.grid, max_changes) {
             return Some(Reflection::Horizontal((), i + 1));
         }
-        if let Some(i) = Self::find_potential_reflection_in(&self.columns, max_changes) {
+        if let Some(i) = Self::find_potential_reflection_in(&self.transposed, max_changes) {
             return Some(Reflection::Vertical((), i + 1));
         }
         None
     }
 
-    /// Helper function to find a reflection in a list of strings. List will
-    /// either be the rows or columns of the pattern, since reflections are
+    /// Helper function to find a reflection between the rows of a grid. The
+    /// grid will either be the pattern or its transpose, since reflections are
     /// either horizontal or vertical.
-    fn find_reflection_in(values: &[String]) -> Option<usize> {
+    fn find_reflection_in(grid: &Grid<char>) -> Option<usize> {
+        let values = grid.rows().collect::<Vec<_>>();
         let mut indices = vec![];
 
         let mut current_index = 0;
@@ -79,7 +72,7 @@ impl Pattern {
             let start_left = index;
             let start_right = index + 1;
 
-            if Self::is_reflection(start_left, start_right, values) {
+            if Self::is_reflection(start_left, start_right, &values) {
                 return Some(index);
             }
         }
@@ -89,15 +82,16 @@ impl Pattern {
     /// Same as `find_reflection_in`, but allows for max_changes changes in the
     /// pattern. Note that this will require at least one change, i.e. won't
     /// return true for exact reflections.
-    fn find_potential_reflection_in(values: &[String], max_changes: usize) -> Option<usize> {
+    fn find_potential_reflection_in(grid: &Grid<char>, max_changes: usize) -> Option<usize> {
+        let values = grid.rows().collect::<Vec<_>>();
         let mut indices = vec![];
         let mut current_index = 0;
         while let Some((index, differences)) = values[current_index..]
             .windows(2)
             .map(|win| {
                 win[0]
-                    .chars()
-                    .zip(win[1].chars())
+                    .iter()
+                    .zip(win[1].iter())
                     .filter(|(a, b)| a != b)
                     .count()
             })
@@ -114,7 +108,7 @@ impl Pattern {
             if Self::is_almost_reflections(
                 start_left,
                 start_right,
-                values,
+                &values,
                 differences,
                 max_changes,
             ) {
@@ -126,7 +120,7 @@ impl Pattern {
 
     /// Check whenever the values at the given indices are a reflection of each
     /// other. Helper function for `find_reflection_in`
-    fn is_reflection(lower: usize, upper: usize, values: &[String]) -> bool {
+    fn is_reflection(lower: usize, upper: usize, values: &[&[char]]) -> bool {
         let max_offset = if lower < (values.len() - upper) {
             lower
         } else {
@@ -149,7 +143,7 @@ impl Pattern {
     fn is_almost_reflections(
         lower: usize,
         upper: usize,
-        values: &[String],
+        values: &[&[char]],
         differences: usize,
         max_changes: usize,
     ) -> bool {
@@ -167,8 +161,8 @@ impl Pattern {
             if top != bottom {
                 // Additional changes required
                 required_changes += top
-                    .chars()
-                    .zip(bottom.chars())
+                    .iter()
+                    .zip(bottom.iter())
                     .filter(|(a, b)| a != b)
                     .count();
             }
@@ -182,19 +176,11 @@ impl Pattern {
 }
 
 fn pattern(input: &str) -> IResult<&str, Pattern> {
-    let (input, rows) = separated_list1(line_ending, take_till(|c| c == '\r'))(input)?;
-    Ok((
-        input,
-        Pattern::from_rows(rows.into_iter().map(|x| x.to_string()).collect()),
-    ))
+    map_res(lines(line), |rows: Vec<&str>| {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).map(Pattern::new)
+    })(input)
 }
 
 pub fn patterns(input: &str) -> IResult<&str, Vec<Pattern>> {
-    let a = input
-        .split("\r\n\r\n")
-        .map(pattern)
-        .filter_map(|x| x.ok())
-        .map(|x| x.1)
-        .collect();
-    Ok(("", a))
+    blocks(pattern)(input)
 }
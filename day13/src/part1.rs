@@ -1,31 +1,30 @@
-use crate::parser::{self, Reflection};
+use aoc_core::{Error, Result};
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, patterns) = parser::patterns(input).ok()?;
+use crate::parser::{Pattern, Reflection};
 
-    Some(
-        patterns
-            .iter()
-            .map(
-                |pattern| match pattern.find_reflection().expect("No reflection found") {
-                    Reflection::Horizontal(_, u) => u * 100,
-                    Reflection::Vertical(_, u) => u,
-                },
-            )
-            .sum::<usize>()
-            .to_string(),
-    )
+pub fn task(patterns: &[Pattern]) -> Result<String> {
+    Ok(patterns
+        .iter()
+        .map(|pattern| match pattern.find_reflection() {
+            Some(Reflection::Horizontal(_, u)) => Ok(u * 100),
+            Some(Reflection::Vertical(_, u)) => Ok(u),
+            None => Err(Error::NoSolution),
+        })
+        .sum::<Result<usize>>()?
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day13;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day13::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "405");
     }
 }
@@ -1,33 +1,30 @@
-use crate::parser::{self, Reflection};
+use aoc_core::{Error, Result};
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, patterns) = parser::patterns(input).ok()?;
-    Some(
-        patterns
-            .iter()
-            .map(|pattern| {
-                match pattern
-                    .find_almost_reflection(1)
-                    .expect("No reflection found")
-                {
-                    Reflection::Horizontal(_, u) => u * 100,
-                    Reflection::Vertical(_, u) => u,
-                }
-            })
-            .sum::<usize>()
-            .to_string(),
-    )
+use crate::parser::{Pattern, Reflection};
+
+pub fn task(patterns: &[Pattern]) -> Result<String> {
+    Ok(patterns
+        .iter()
+        .map(|pattern| match pattern.find_almost_reflection(1) {
+            Some(Reflection::Horizontal(_, u)) => Ok(u * 100),
+            Some(Reflection::Vertical(_, u)) => Ok(u),
+            None => Err(Error::NoSolution),
+        })
+        .sum::<Result<usize>>()?
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day13;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day13::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "400");
     }
 }
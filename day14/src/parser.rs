@@ -1,26 +1,19 @@
-use nom::bytes::complete::take_till;
-use nom::character::complete::line_ending;
-use nom::multi::separated_list1;
-use nom::IResult;
+use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use aoc_core::parse::{line, lines, Expected, IResult, ParseError};
+use aoc_grid::{Direction, Grid, Pos};
+use nom::combinator::map_res;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Tile {
     Empty,
     RoundRock,
     StaticObstacle,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum PossibleMovementResult {
-    Possible((usize, usize)),
+    Possible(Pos),
     Blocked,
     OutOfBounds,
 }
@@ -28,9 +21,25 @@ enum PossibleMovementResult {
 #[derive(Debug, Clone)]
 pub struct Board {
     /// Collection of (x, y) coordinates of round rocks
-    round_rocks: Vec<(usize, usize)>,
+    round_rocks: Vec<Pos>,
     /// Tiles
-    tiles: Vec<Vec<Tile>>,
+    tiles: Grid<Tile>,
+}
+
+// Boards are equal if their tiles are, regardless of the order in which the
+// round rocks are listed
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.tiles == other.tiles
+    }
+}
+
+impl Eq for Board {}
+
+impl Hash for Board {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.tiles.hash(state);
+    }
 }
 
 impl Board {
@@ -49,18 +58,17 @@ impl Board {
         let mut changes = Vec::new();
 
         self.round_rocks.iter().enumerate().for_each(|(i, (x, y))| {
-            if let PossibleMovementResult::Possible((xn, yn)) =
-                self.possible_movement(*x, *y, direction)
+            if let PossibleMovementResult::Possible(next) =
+                self.possible_movement((*x, *y), direction)
             {
-                changes.push((i, (xn, yn)));
+                changes.push((i, next));
             }
         });
 
-        changes.iter().for_each(|(i, (xn, yn))| {
-            let (x_old, y_old) = self.round_rocks[*i];
-            self.tiles[y_old][x_old] = Tile::Empty;
-            self.tiles[*yn][*xn] = Tile::RoundRock;
-            self.round_rocks[*i] = (*xn, *yn);
+        changes.iter().for_each(|&(i, next)| {
+            self.tiles[self.round_rocks[i]] = Tile::Empty;
+            self.tiles[next] = Tile::RoundRock;
+            self.round_rocks[i] = next;
         });
 
         changes.len() as _
@@ -69,7 +77,7 @@ impl Board {
     /// Calculate the total load of the board in its current state, as defined
     /// by the problem
     pub fn total_load(&self) -> u32 {
-        let height = self.tiles.len();
+        let height = self.tiles.height();
         self.round_rocks
             .iter()
             .fold(0, |acc, (_x, y)| acc + (height - y) as u32)
@@ -77,25 +85,13 @@ impl Board {
 
     /// Calculate the possible movement of a rock at position (x, y) in the
     /// given  direction.
-    fn possible_movement(
-        &self,
-        x: usize,
-        y: usize,
-        direction: Direction,
-    ) -> PossibleMovementResult {
-        let width = self.tiles[0].len() - 1;
-        let height = self.tiles.len() - 1;
-
-        let (xn, yn) = match direction {
-            Direction::North if y > 0 => (x, y - 1),
-            Direction::South if y < height => (x, y + 1),
-            Direction::East if x < width => (x + 1, y),
-            Direction::West if x > 0 => (x - 1, y),
-            _ => return PossibleMovementResult::OutOfBounds,
+    fn possible_movement(&self, pos: Pos, direction: Direction) -> PossibleMovementResult {
+        let Some(next) = self.tiles.step(pos, direction) else {
+            return PossibleMovementResult::OutOfBounds;
         };
 
-        if let Tile::Empty = self.tiles[yn][xn] {
-            PossibleMovementResult::Possible((xn, yn))
+        if let Tile::Empty = self.tiles[next] {
+            PossibleMovementResult::Possible(next)
         } else {
             PossibleMovementResult::Blocked
         }
@@ -103,43 +99,33 @@ impl Board {
 }
 
 pub fn board(input: &str) -> IResult<&str, Board> {
-    let (input, rows) = separated_list1(line_ending, row)(input)?;
-
-    let mut round_rocks = Vec::new();
-
-    rows.iter()
-        .enumerate()
-        .for_each(|(r, (_, round_column_i))| {
-            round_column_i.iter().for_each(|c| {
-                round_rocks.push((*c, r));
-            });
-        });
+    let (input, tiles) = map_res(lines(row), Grid::from_rows)(input)?;
+    let round_rocks = tiles
+        .iter()
+        .filter(|(_, &tile)| tile == Tile::RoundRock)
+        .map(|(pos, _)| pos)
+        .collect();
 
-    Ok((
-        input,
-        Board {
-            round_rocks,
-            tiles: rows.into_iter().map(|(tiles, _)| tiles).collect(),
-        },
-    ))
+    Ok((input, Board { round_rocks, tiles }))
 }
 
-fn row(input: &str) -> IResult<&str, (Vec<Tile>, Vec<usize>)> {
-    let mut round_rock_column_indices = Vec::new();
-
-    let (input, tiles) = take_till(|c| c == '\r')(input)?;
-    let tiles: Vec<Tile> = tiles
-        .char_indices()
-        .map(|(i, c)| match c {
-            'O' => {
-                round_rock_column_indices.push(i);
-                Tile::RoundRock
-            }
+fn row(input: &str) -> IResult<&str, Vec<Tile>> {
+    let (input, line) = line(input)?;
+    let mut tiles = Vec::with_capacity(line.len());
+    for (i, c) in line.char_indices() {
+        tiles.push(match c {
+            'O' => Tile::RoundRock,
             '#' => Tile::StaticObstacle,
             '.' => Tile::Empty,
-            _ => panic!("Invalid character in board"),
-        })
-        .collect();
+            _ => {
+                return Err(nom::Err::Failure(ParseError {
+                    input: &line[i..],
+                    expected: ['O', '#', '.'].map(Expected::Char).to_vec(),
+                    context: None,
+                }))
+            }
+        });
+    }
 
-    Ok((input, (tiles, round_rock_column_indices)))
+    Ok((input, tiles))
 }
@@ -1,20 +1,25 @@
-use crate::parser::{self, Direction};
+use aoc_core::Result;
+use aoc_grid::Direction;
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, mut board) = parser::board(input).ok()?;
-    board.move_round_rocks(Direction::North);
-    Some(board.total_load().to_string())
+use crate::parser::Board;
+
+pub fn task(board: &Board) -> Result<String> {
+    let mut board = board.clone();
+    board.move_round_rocks(Direction::Up);
+    Ok(board.total_load().to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day14;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day14::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "136");
     }
 }
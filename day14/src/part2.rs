@@ -1,60 +1,43 @@
-use crate::parser::{self, Direction};
+use aoc_core::{find_cycle, Result};
+use aoc_grid::Direction;
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, mut board) = parser::board(input).ok()?;
+use crate::parser::Board;
 
+/// Number of spin cycles from the problem description
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+pub fn task(board: &Board) -> Result<String> {
     let cycle = [
-        Direction::North,
-        Direction::West,
-        Direction::South,
-        Direction::East,
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
     ];
 
-    // Achieve stable state, 250 cycles
-    for _ in 0..250 {
-        for direction in &cycle {
-            board.move_round_rocks(*direction);
-        }
-    }
-
-    // Find cycle with len count
-    let current_load = board.total_load();
-    let mut count = 0;
-    loop {
-        for direction in &cycle {
-            board.move_round_rocks(*direction);
-        }
-
-        let new_load = board.total_load();
-        count += 1;
-        if current_load == new_load {
-            break;
+    // The board eventually repeats itself, after which every spin cycle is
+    // known without simulating it
+    let spins = find_cycle(board.clone(), |board| {
+        let mut board = board.clone();
+        for direction in cycle {
+            board.move_round_rocks(direction);
         }
-    }
-
-    // Find remaining cycles after `(1_000_000_000 - 250) / count` iterations
-    let remaining = (1_000_000_000 - 250) % count;
-
-    // Manually iterate remaining cycles
-    for _ in 0..remaining {
-        for direction in &cycle {
-            board.move_round_rocks(*direction);
-        }
-    }
+        board
+    });
 
-    Some(board.total_load().to_string())
+    Ok(spins.state_after(SPIN_CYCLES).total_load().to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day14;
 
     #[test]
-    #[ignore = "Somehow off by one (correct for input)"]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day14::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "64");
     }
 }
@@ -1,25 +1,27 @@
-pub fn task(input: &str) -> Option<String> {
-    Some(
-        input
-            .split(',')
-            .map(|s| {
-                s.bytes()
-                    .fold(0u32, |acc, b| ((acc + (b as u32)) * 17u32) % 256u32)
-            })
-            .sum::<u32>()
-            .to_string(),
-    )
+use aoc_core::Result;
+
+pub fn task(steps: &[&str]) -> Result<String> {
+    Ok(steps
+        .iter()
+        .map(|s| {
+            s.bytes()
+                .fold(0u32, |acc, b| ((acc + (b as u32)) * 17u32) % 256u32)
+        })
+        .sum::<u32>()
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day15;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day15::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "1320");
     }
 }
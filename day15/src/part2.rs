@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
+use aoc_core::{Error, Result};
 use itertools::Itertools;
 
-pub fn task(input: &str) -> Option<String> {
+pub fn task<'a>(steps: &[&'a str]) -> Result<String> {
     // Hashmap mapping box number to a vector of tuples containing the label and
     // focal length
-    let mut boxes: HashMap<u32, Vec<(&str, u32)>> = HashMap::new();
+    let mut boxes: HashMap<u32, Vec<(&'a str, u32)>> = HashMap::new();
 
-    input.split(',').for_each(|s| {
+    for &s in steps {
         let (box_nr, idx) = s
             .bytes()
             .enumerate()
@@ -17,16 +18,14 @@ pub fn task(input: &str) -> Option<String> {
             });
 
         let label = &s[..idx + 1];
-        let operator = s.as_bytes()[idx + 1];
-
-        match operator {
-            b'=' => {
+        match s.as_bytes().get(idx + 1) {
+            Some(b'=') => {
                 let focal_length = s[idx + 2..]
                     .chars()
                     .take_while(char::is_ascii_digit)
                     .collect::<String>()
                     .parse::<u32>()
-                    .unwrap();
+                    .map_err(|_| Error::invalid(format!("missing focal length in {:?}", s)))?;
 
                 // Modify if exists, otherwise insert new
                 boxes
@@ -40,41 +39,41 @@ pub fn task(input: &str) -> Option<String> {
                     })
                     .or_insert(vec![(label, focal_length)]);
             }
-            b'-' => {
+            Some(b'-') => {
                 boxes.entry(box_nr).and_modify(|v| {
                     if let Some((idx, _)) = v.iter().find_position(|(l, _)| *l == label) {
                         v.remove(idx);
                     }
                 });
             }
-            _ => panic!("Invalid operator"),
+            _ => return Err(Error::invalid(format!("missing operation in {:?}", s))),
         }
-    });
+    }
 
     // Calculate the total focusing power
-    Some(
-        boxes
-            .iter()
-            .map(|(box_nr, seq)| {
-                seq.iter()
-                    .enumerate()
-                    .map(|(i, (_, focal_length))| (1 + box_nr) * (i as u32 + 1) * focal_length)
-                    .sum::<u32>()
-            })
-            .sum::<u32>()
-            .to_string(),
-    )
+    Ok(boxes
+        .iter()
+        .map(|(box_nr, seq)| {
+            seq.iter()
+                .enumerate()
+                .map(|(i, (_, focal_length))| (1 + box_nr) * (i as u32 + 1) * focal_length)
+                .sum::<u32>()
+        })
+        .sum::<u32>()
+        .to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day15;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day15::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "145");
     }
 }
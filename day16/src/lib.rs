@@ -1,2 +1,26 @@
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Result, Solution};
+use aoc_grid::Grid;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+    type Input<'a> = Grid<parser::Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parser::contraption(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
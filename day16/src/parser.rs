@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use aoc_core::Result;
+use aoc_grid::{Direction, Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Empty,
+    MirrorRight,
+    MirrorLeft,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl Tile {
+    /// Directions in which a beam moving in `direction` leaves the tile.
+    pub fn deflect(self, direction: Direction) -> impl Iterator<Item = Direction> {
+        let beams = match (self, direction.is_horizontal()) {
+            (Tile::Empty, _) => [Some(direction), None],
+            // `/`
+            (Tile::MirrorRight, true) => [Some(direction.turn_left()), None],
+            (Tile::MirrorRight, false) => [Some(direction.turn_right()), None],
+            // `\`
+            (Tile::MirrorLeft, true) => [Some(direction.turn_right()), None],
+            (Tile::MirrorLeft, false) => [Some(direction.turn_left()), None],
+            // Pointy end of a splitter
+            (Tile::HorizontalSplitter, true) | (Tile::VerticalSplitter, false) => {
+                [Some(direction), None]
+            }
+            // Flat side of a splitter
+            (Tile::HorizontalSplitter, false) | (Tile::VerticalSplitter, true) => {
+                [Some(direction.turn_left()), Some(direction.turn_right())]
+            }
+        };
+        beams.into_iter().flatten()
+    }
+}
+
+/// Parse the contraption. All rows must have the same, non-zero, length.
+pub fn contraption(input: &str) -> Result<Grid<Tile>> {
+    Grid::parse(input, |c| match c {
+        '.' => Ok(Tile::Empty),
+        '/' => Ok(Tile::MirrorRight),
+        '\\' => Ok(Tile::MirrorLeft),
+        '-' => Ok(Tile::HorizontalSplitter),
+        '|' => Ok(Tile::VerticalSplitter),
+        _ => Err("expected one of '.', '/', '\\', '-' or '|'"),
+    })
+}
+
+/// Number of tiles energized by a beam entering the contraption at `start`,
+/// moving in `direction`.
+pub fn energized_tiles(contraption: &Grid<Tile>, start: Pos, direction: Direction) -> usize {
+    // Set containing beams that have already happened to prevent infinite loops
+    let mut already_happened = HashSet::new();
+    // Active beams, with the tile they are entering
+    let mut beams = vec![(start, direction)];
+
+    while let Some((pos, direction)) = beams.pop() {
+        if !already_happened.insert((pos, direction)) {
+            continue;
+        }
+        for direction in contraption[pos].deflect(direction) {
+            if let Some(next) = contraption.step(pos, direction) {
+                beams.push((next, direction));
+            }
+        }
+    }
+
+    already_happened
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len()
+}
@@ -1,2 +1,26 @@
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{Result, Solution};
+use aoc_grid::Grid;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+    /// Heat loss of each city block
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid::parse(input, |c| c.to_digit(10).ok_or("expected a digit"))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,33 +1,16 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap};
+use aoc_core::{Error, Result};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_search::dijkstra;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, PartialOrd, Ord)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Node {
-    pos: (i32, i32),
+    pos: Pos,
     dir: Direction,
     remaining_dir: u8,
 }
 
-pub fn task(input: &str) -> Option<String> {
-    let width = input.lines().next()?.len();
-    let height = input.lines().count();
-    let map: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| line.chars().filter_map(|c| c.to_digit(10)).collect())
-        .collect();
-
-    let goal = (width as i32 - 1, height as i32 - 1);
-
-    let mut costs: HashMap<Node, u32> = HashMap::new();
-    let mut queue: BinaryHeap<Reverse<(u32, Node)>> = BinaryHeap::new();
+pub fn task(map: &Grid<u32>) -> Result<String> {
+    let goal = (map.width() - 1, map.height() - 1);
 
     let start_node = Node {
         pos: (0, 0),
@@ -35,83 +18,59 @@ pub fn task(input: &str) -> Option<String> {
         dir: Direction::Right,
     };
 
-    costs.insert(start_node, 0);
-    queue.push(Reverse((0, start_node)));
-
-    while let Some(Reverse((cost, node))) = queue.pop() {
-        if node.pos == goal {
-            return Some(cost.to_string());
-        }
-
-        if cost > *costs.get(&node).unwrap_or(&u32::MAX) {
-            continue;
-        }
-
-        for n_node in get_neighbors(&node, (width as i32, height as i32)) {
-            let next_pos = n_node.pos;
-            let next_cost = cost + map[next_pos.1 as usize][next_pos.0 as usize];
-
-            if next_cost < *costs.get(&n_node).unwrap_or(&u32::MAX) {
-                queue.push(Reverse((next_cost, n_node)));
-                costs.insert(n_node, next_cost);
-            }
-        }
-    }
-    None
+    dijkstra(
+        start_node,
+        |node| get_neighbors(node, map).map(|n_node| (n_node, map[n_node.pos])),
+        |node| node.pos == goal,
+    )
+    .map(|path| path.cost.to_string())
+    .ok_or(Error::NoSolution)
 }
 
-fn get_neighbors(node: &Node, (width, height): (i32, i32)) -> impl Iterator<Item = Node> + '_ {
-    let (x, y) = node.pos;
+fn get_neighbors<'a>(node: &Node, map: &'a Grid<u32>) -> impl Iterator<Item = Node> + 'a {
+    let pos = node.pos;
     let current_dir = node.dir;
     let c_remaining_dir = node.remaining_dir;
-    [
-        ((x - 1, y), Direction::Left),
-        ((x + 1, y), Direction::Right),
-        ((x, y - 1), Direction::Up),
-        ((x, y + 1), Direction::Down),
-    ]
-    .into_iter()
-    // Bounds check
-    .filter(move |&((x, y), _)| x >= 0 && y >= 0 && x < width && y < height)
-    // Do not allow backwards movement
-    .filter(move |(_, n_dir)| match n_dir {
-        Direction::Left => current_dir != Direction::Right,
-        Direction::Right => current_dir != Direction::Left,
-        Direction::Up => current_dir != Direction::Down,
-        Direction::Down => current_dir != Direction::Up,
-    })
-    // Restriction on movement - need to move in same direction 4 times in a row
-    // before being able to change direction, and can only walk in the same direction
-    // 10 times in a row
-    .filter_map(move |(pos, dir)| {
-        if c_remaining_dir == 0 || (c_remaining_dir > 7 && current_dir != dir) {
-            return None;
-        }
-
-        // Update remaining_dir
-        let remaining_dir = if current_dir == dir {
-            c_remaining_dir - 1
-        } else {
-            10
-        };
+    Direction::ALL
+        .into_iter()
+        // Do not allow backwards movement
+        .filter(move |&n_dir| n_dir != current_dir.opposite())
+        // Bounds check
+        .filter_map(move |n_dir| map.step(pos, n_dir).map(|pos| (pos, n_dir)))
+        // Restriction on movement - need to move in same direction 4 times in a row
+        // before being able to change direction, and can only walk in the same direction
+        // 10 times in a row
+        .filter_map(move |(pos, dir)| {
+            if c_remaining_dir == 0 || (c_remaining_dir > 7 && current_dir != dir) {
+                return None;
+            }
 
-        (remaining_dir > 0).then_some(Node {
-            pos,
-            dir,
-            remaining_dir,
+            // Update remaining_dir
+            let remaining_dir = if current_dir == dir {
+                c_remaining_dir - 1
+            } else {
+                10
+            };
+
+            (remaining_dir > 0).then_some(Node {
+                pos,
+                dir,
+                remaining_dir,
+            })
         })
-    })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day17;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day17::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "94");
     }
 }
@@ -1,16 +1,10 @@
-use nom::bytes::complete::{tag, take_till};
-use nom::character::complete::{anychar, digit0, line_ending, space1};
-use nom::multi::separated_list1;
+use aoc_core::parse::{lines, tag, IResult};
+use aoc_grid::Direction;
+use nom::branch::alt;
+use nom::bytes::complete::take_till;
+use nom::character::complete::{self, char, space1};
+use nom::combinator::value;
 use nom::sequence::{delimited, preceded, separated_pair};
-use nom::IResult;
-
-#[derive(Debug, PartialEq, Eq, Clone, Hash, Copy)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Instruction<'a> {
@@ -20,22 +14,16 @@ pub struct Instruction<'a> {
 }
 
 fn direction(input: &str) -> IResult<&str, Direction> {
-    anychar(input).map(|(i, dir)| {
-        (
-            i,
-            match dir {
-                'U' => Direction::Up,
-                'D' => Direction::Down,
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                _ => panic!("invalid direction"),
-            },
-        )
-    })
+    alt((
+        value(Direction::Up, char('U')),
+        value(Direction::Down, char('D')),
+        value(Direction::Left, char('L')),
+        value(Direction::Right, char('R')),
+    ))(input)
 }
 
 fn distance(input: &str) -> IResult<&str, u32> {
-    digit0(input).map(|(i, c)| (i, c.parse().expect("distance is a digit")))
+    complete::u32(input)
 }
 
 pub fn color(input: &str) -> IResult<&str, &str> {
@@ -46,7 +34,7 @@ pub fn color(input: &str) -> IResult<&str, &str> {
     )(input)
 }
 
-pub fn instruction(input: &str) -> IResult<&str, Instruction> {
+pub fn instruction(input: &str) -> IResult<&str, Instruction<'_>> {
     let (input, (direction, distance)) = separated_pair(direction, space1, distance)(input)?;
     let (input, color) = preceded(space1, color)(input)?;
 
@@ -60,6 +48,6 @@ pub fn instruction(input: &str) -> IResult<&str, Instruction> {
     ))
 }
 
-pub fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
-    separated_list1(line_ending, instruction)(input)
+pub fn instructions(input: &str) -> IResult<&str, Vec<Instruction<'_>>> {
+    lines(instruction)(input)
 }
@@ -1,118 +1,62 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
 
-use crate::parser::{self};
+use aoc_core::{Error, Result};
+use aoc_search::reachable;
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, instructions) = parser::instructions(input).ok()?;
+use crate::parser::Instruction;
 
+pub fn task(instructions: &[Instruction]) -> Result<String> {
     let mut x = 0;
     let mut y = 0;
 
-    let mut right = HashSet::new();
-    let mut left = HashSet::new();
-
-    let positions: HashSet<(i32, i32)> = instructions
+    let positions: HashSet<(isize, isize)> = instructions
         .iter()
         .flat_map(|instruction| {
-            let dist = instruction.distance as i32;
-            let x_l = x;
-            let y_l = y;
-            match instruction.direction {
-                parser::Direction::Up => {
-                    y -= dist;
-                    (0..dist)
-                        .map(|i| {
-                            let (nx, ny) = (x_l, y_l - i);
-                            right.insert((nx + 1, ny));
-                            left.insert((nx - 1, ny));
-                            (nx, ny)
-                        })
-                        .collect::<Vec<_>>()
-                }
-                parser::Direction::Down => {
-                    y += dist;
-                    (0..dist)
-                        .map(|i| {
-                            let (nx, ny) = (x_l, y_l + i);
-                            right.insert((nx - 1, ny));
-                            left.insert((nx + 1, ny));
-                            (nx, ny)
-                        })
-                        .collect()
-                }
-                parser::Direction::Left => {
-                    x -= dist;
-                    (0..dist)
-                        .map(|i| {
-                            let (nx, ny) = (x_l - i, y_l);
-                            right.insert((nx, ny - 1));
-                            left.insert((nx, ny + 1));
-                            (nx, ny)
-                        })
-                        .collect()
-                }
-                parser::Direction::Right => {
-                    x += dist;
-                    (0..dist)
-                        .map(|i| {
-                            let (nx, ny) = (x_l + i, y_l);
-                            right.insert((nx, ny + 1));
-                            left.insert((nx, ny - 1));
-                            (nx, ny)
-                        })
-                        .collect()
-                }
-            }
+            let (dx, dy) = instruction.direction.offset();
+            let (x_l, y_l) = (x, y);
+            let dist = instruction.distance as isize;
+            x += dx * dist;
+            y += dy * dist;
+            (0..dist).map(move |i| (x_l + dx * i, y_l + dy * i))
         })
         .collect();
 
-    let right_c = right
-        .iter()
-        .filter(|(x, y)| positions.contains(&(*x, *y)))
-        .count();
-
-    let left_c = left
+    // Bounding box of the trench, with a margin of one so that the outside
+    // is connected all around it
+    let (min_x, max_x, min_y, max_y) = positions
         .iter()
-        .filter(|(x, y)| positions.contains(&(*x, *y)))
-        .count();
-
-    // Find the starting position, based on which side has more positions
-    let start_pos = if right_c > left_c {
-        *right.iter().next().unwrap()
-    } else {
-        *left.iter().next().unwrap()
-    };
-
-    // Set of all in
-    let mut inner = HashSet::new();
-    let mut queue = VecDeque::from(vec![start_pos]);
-
-    // Perform BFS to find all inner positions
-    while let Some((x, y)) = queue.pop_front() {
-        if inner.contains(&(x, y)) {
-            continue;
-        }
-        inner.insert((x, y));
-
-        for (nx, ny) in &[(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
-            if !positions.contains(&(*nx, *ny)) {
-                queue.push_back((*nx, *ny));
-            }
-        }
-    }
-
-    Some((inner.len() + positions.len()).to_string())
+        .fold(None, |bounds, &(x, y)| {
+            let (min_x, max_x, min_y, max_y) = bounds.unwrap_or((x, x, y, y));
+            Some((min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y)))
+        })
+        .map(|(min_x, max_x, min_y, max_y)| (min_x - 1, max_x + 1, min_y - 1, max_y + 1))
+        .ok_or_else(|| Error::invalid("empty dig plan"))?;
+
+    // Perform BFS to find all positions outside the trench, everything else
+    // is dug out
+    let outside = reachable((min_x, min_y), |&(x, y)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y))
+            .filter(|pos| !positions.contains(pos))
+            .collect::<Vec<_>>()
+    });
+
+    let area = (max_x - min_x + 1) * (max_y - min_y + 1);
+    Ok((area as usize - outside.len()).to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day18;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day18::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "62");
     }
 }
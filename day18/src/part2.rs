@@ -1,4 +1,7 @@
-use crate::parser::{self, Direction};
+use aoc_core::{Error, Result};
+use aoc_grid::Direction;
+
+use crate::parser::Instruction;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Rotation {
@@ -14,17 +17,20 @@ struct Side {
     length: u32,
 }
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, instructions) = parser::instructions(input).ok()?;
-
+pub fn task(instructions: &[Instruction]) -> Result<String> {
     // Convert to correct instructions as described in part 2
     let mut x = 0;
     let mut y = 0;
     let mut sides = instructions
-        .into_iter()
-        .filter_map(|instruction| {
-            let distance: u32 = u32::from_str_radix(&instruction.color[..5], 16).ok()?;
-            Some(match &instruction.color[5..] {
+        .iter()
+        .map(|instruction| {
+            let color = instruction.color;
+            let invalid = || Error::invalid(format!("invalid color #{}", color));
+            if color.len() != 6 || !color.is_ascii() {
+                return Err(invalid());
+            }
+            let distance: u32 = u32::from_str_radix(&color[..5], 16).map_err(|_| invalid())?;
+            Ok(match &color[5..] {
                 "0" => {
                     let side = Side {
                         x,
@@ -65,12 +71,15 @@ pub fn task(input: &str) -> Option<String> {
                     y -= distance as i128;
                     side
                 }
-                _ => return None,
+                _ => return Err(invalid()),
             })
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
 
-    let last_direction = sides.last()?.direction;
+    let last_direction = sides
+        .last()
+        .ok_or_else(|| Error::invalid("empty dig plan"))?
+        .direction;
     let rotation = match last_direction {
         Direction::Up | Direction::Right => Rotation::Clockwise,
         Direction::Down | Direction::Left => Rotation::CounterClockwise,
@@ -90,25 +99,28 @@ pub fn task(input: &str) -> Option<String> {
         })
         .fold(0i128, |acc, (x1, y1, x2, y2)| acc + (x1 * y2) - (y1 * x2));
 
-    a += sides.last()?.x * sides.first()?.y;
-    a -= sides.last()?.y * sides.first()?.x;
+    let (first, last) = (sides[0], sides[sides.len() - 1]);
+    a += last.x * first.y;
+    a -= last.y * first.x;
 
     let edge = sides.iter().fold(0u32, |acc, side| acc + side.length) as i128;
 
     // Not sure why one needs to be added here, but it works
     let area = (a.abs() + edge) / 2 + 1;
-    Some(area.to_string())
+    Ok(area.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day18;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day18::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "952408144115");
     }
 }
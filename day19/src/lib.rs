@@ -1,3 +1,27 @@
-pub(crate) mod parser;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::{parse, Result, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+    type Input<'a> = parser::System<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let system = parse::all(input, parser::system)?;
+        system.validate()?;
+        Ok(system)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<String> {
+        part1::task(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<String> {
+        part2::task(input)
+    }
+}
@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
+use aoc_core::parse::{lines, tag, IResult};
+use aoc_core::Error;
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take_till};
+use nom::bytes::complete::take_till;
 use nom::character::complete::{self, line_ending};
 use nom::multi::{many0, separated_list1};
 use nom::sequence::{delimited, preceded, tuple};
-use nom::IResult;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum PartCategory {
@@ -81,6 +82,38 @@ impl<'a> Rule<'a> {
     }
 }
 
+/// The workflows by name, and the parts to sort.
+#[derive(Debug)]
+pub struct System<'a> {
+    pub rules: HashMap<&'a str, Rule<'a>>,
+    pub parts: Vec<Part>,
+}
+
+impl System<'_> {
+    /// Check that the workflow `in` exists, and that every workflow only sends
+    /// parts to `A`, `R` or another existing workflow.
+    pub fn validate(&self) -> Result<(), Error> {
+        if !self.rules.contains_key("in") {
+            return Err(Error::invalid("missing workflow in"));
+        }
+        for (name, rule) in &self.rules {
+            if rule.comparisons.is_empty() {
+                return Err(Error::invalid(format!("workflow {} has no rules", name)));
+            }
+            for comparison in &rule.comparisons {
+                let target = comparison.result;
+                if target != "A" && target != "R" && !self.rules.contains_key(target) {
+                    return Err(Error::invalid(format!(
+                        "workflow {} refers to unknown workflow {}",
+                        name, target
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 fn part_category(input: &str) -> IResult<&str, PartCategory> {
     alt((
         complete::char('x'),
@@ -115,7 +148,7 @@ fn comparison_result(input: &str) -> IResult<&str, &str> {
     preceded(tag(":"), take_till(|c| c == ','))(input)
 }
 
-fn comparison(input: &str) -> IResult<&str, Comparison> {
+fn comparison(input: &str) -> IResult<&str, Comparison<'_>> {
     tuple((
         part_category,
         comparison_type,
@@ -133,7 +166,7 @@ fn comparison(input: &str) -> IResult<&str, Comparison> {
     })
 }
 
-fn rule(input: &str) -> IResult<&str, (&str, Rule)> {
+fn rule(input: &str) -> IResult<&str, (&str, Rule<'_>)> {
     let (input, name) = take_till(|c| c == '{')(input)?;
     let (input, mut comparisons) =
         preceded(tag("{"), separated_list1(tag(","), comparison))(input)?;
@@ -149,8 +182,8 @@ fn rule(input: &str) -> IResult<&str, (&str, Rule)> {
     Ok((input, (name, Rule { comparisons })))
 }
 
-pub fn rules(input: &str) -> IResult<&str, HashMap<&str, Rule>> {
-    separated_list1(tag("\r\n"), rule)(input).map(|(input, rules)| {
+pub fn rules(input: &str) -> IResult<&str, HashMap<&str, Rule<'_>>> {
+    lines(rule)(input).map(|(input, rules)| {
         let rules = rules.into_iter().collect();
         (input, rules)
     })
@@ -176,7 +209,13 @@ fn part(input: &str) -> IResult<&str, Part> {
 }
 
 pub fn parts(input: &str) -> IResult<&str, Vec<Part>> {
-    preceded(many0(line_ending), separated_list1(line_ending, part))(input)
+    preceded(many0(line_ending), lines(part))(input)
+}
+
+pub fn system(input: &str) -> IResult<&str, System<'_>> {
+    let (input, rules) = rules(input)?;
+    let (input, parts) = parts(input)?;
+    Ok((input, System { rules, parts }))
 }
 
 #[cfg(test)]
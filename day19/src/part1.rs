@@ -1,19 +1,17 @@
 use std::collections::HashMap;
 
-use crate::parser::{self, Part, Rule};
-
-pub fn task(input: &str) -> Option<String> {
-    let (input, rules) = parser::rules(input).ok()?;
-    let (_, parts) = parser::parts(input).ok()?;
-
-    Some(
-        parts
-            .into_iter()
-            .filter(|part| process_part(part, &rules))
-            .map(|part| part.x + part.m + part.a + part.s)
-            .sum::<u32>()
-            .to_string(),
-    )
+use aoc_core::Result;
+
+use crate::parser::{Part, Rule, System};
+
+pub fn task(system: &System) -> Result<String> {
+    Ok(system
+        .parts
+        .iter()
+        .filter(|part| process_part(part, &system.rules))
+        .map(|part| part.x + part.m + part.a + part.s)
+        .sum::<u32>()
+        .to_string())
 }
 
 fn process_part(part: &Part, rules: &HashMap<&str, Rule>) -> bool {
@@ -31,13 +29,15 @@ fn process_part(part: &Part, rules: &HashMap<&str, Rule>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day19;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day19::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "19114");
     }
 }
@@ -1,74 +1,68 @@
 use std::collections::HashMap;
-use std::ops::Range;
 
-use crate::parser::{rules, ComparisonType, PartCategory, Rule};
+use aoc_core::Result;
+use aoc_interval::Interval;
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, rules) = rules(input).ok()?;
+use crate::parser::{ComparisonType, PartCategory, Rule, System};
 
-    Some(valid_combinations("in", [1..4000, 1..4000, 1..4000, 1..4000], &rules).to_string())
+/// Smallest and largest rating of each category.
+const MIN_RATING: u32 = 1;
+const MAX_RATING: u32 = 4000;
+
+pub fn task(system: &System) -> Result<String> {
+    let ratings = Interval::inclusive(MIN_RATING, MAX_RATING).expect("Ratings fit in u32");
+    Ok(valid_combinations("in", [ratings; 4], &system.rules).to_string())
 }
 
 /// Recursively compute the number of valid combinations for a map of rules
-/// and ranges of values for each part category.
+/// and intervals of values for each part category.
 fn valid_combinations(
     current_rule: &str,
-    range: [Range<u32>; 4],
+    ranges: [Interval<u32>; 4],
     rules: &HashMap<&str, Rule>,
-) -> u64 {
+) -> u128 {
     match current_rule {
-        "A" => {
-            return range.into_iter().map(|r| (r.count() + 1) as u64).product();
-        }
+        "A" => return ranges.iter().map(Interval::len).product(),
         "R" => return 0,
         _ => {}
     }
 
-    let rule = rules.get(current_rule).expect("Expected rule to exist");
-    let mut current_range = range;
+    // Workflows are validated when parsing, so the rule always exists
+    let rule = &rules[current_rule];
+    let mut remaining = ranges;
     let mut combinations = 0;
 
     for comp in &rule.comparisons {
         let next = comp.result;
-        // Get relevant range, if it unwraps we are guaranteed to have NoComparison as
-        // kind
-        let range_index = part_category_to_range_index(
-            comp.part_category.as_ref().unwrap_or(&PartCategory::Cool),
-        );
+        let Some(category) = &comp.part_category else {
+            // The final comparison sends everything that is left
+            combinations += valid_combinations(next, remaining, rules);
+            break;
+        };
+        let index = part_category_to_range_index(category);
 
-        match comp.kind {
+        // Split the interval of the category into the values passing the
+        // comparison, which go to the next rule, and the values left for the
+        // following comparisons
+        let (passed, failed) = match comp.kind {
             ComparisonType::GreaterThan => {
-                let mut range = current_range.clone();
-                if range[range_index].end > comp.value {
-                    // Compute new ranges, `range` contains range that are valid for
-                    // the next rule `current_range` contains ranges that remain for
-                    // next comparisons
-                    let lower = range[range_index].start.min(comp.value);
-                    let upper = range[range_index].start.max(comp.value);
-                    range[range_index].start = upper + 1;
-                    current_range[range_index].start = lower;
-                    current_range[range_index].end = upper;
-                    combinations += valid_combinations(next, range, rules);
-                }
-            }
-            ComparisonType::LessThan => {
-                let mut range = current_range.clone();
-
-                if range[range_index].start < comp.value {
-                    // Compute new ranges, `range` contains range that are valid for
-                    // the next rule `current_range` contains ranges that remain for
-                    // next comparisons
-                    let lower = range[range_index].end.min(comp.value);
-                    let upper = range[range_index].end.max(comp.value);
-                    range[range_index].end = lower - 1;
-                    current_range[range_index].start = lower;
-                    current_range[range_index].end = upper;
-                    combinations += valid_combinations(next, range, rules);
-                }
+                let (below, above) = remaining[index].split_at(comp.value.saturating_add(1));
+                (above, below)
             }
+            ComparisonType::LessThan => remaining[index].split_at(comp.value),
             ComparisonType::NoComparison => {
-                combinations += valid_combinations(next, current_range.clone(), rules);
+                unreachable!("Only the final comparison has no category")
             }
+        };
+
+        if let Some(passed) = passed {
+            let mut ranges = remaining;
+            ranges[index] = passed;
+            combinations += valid_combinations(next, ranges, rules);
+        }
+        match failed {
+            Some(failed) => remaining[index] = failed,
+            None => break,
         }
     }
 
@@ -86,13 +80,15 @@ fn part_category_to_range_index(part_category: &PartCategory) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day19;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day19::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "167409079868000");
     }
 }
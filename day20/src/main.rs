@@ -1,13 +1,7 @@
-use day20::{part1, part2};
+use std::process::ExitCode;
 
-fn main() {
-    // Output results for both parts
-    println!(
-        "Part 1:\n  {}",
-        part1::task(include_str!("../input.txt")).unwrap_or("No solution found".to_string())
-    );
-    println!(
-        "Part 2:\n  {}",
-        part2::task(include_str!("../input.txt")).unwrap_or("No solution found".to_string())
-    );
+use day20::Day20;
+
+fn main() -> ExitCode {
+    aoc_core::run::<Day20>(env!("CARGO_MANIFEST_DIR"))
 }
@@ -1,13 +1,12 @@
 use std::collections::HashMap;
 
+use aoc_core::parse::{lines, tag, IResult};
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take_till};
-use nom::character::complete::line_ending;
+use nom::bytes::complete::take_till;
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, tuple};
-use nom::IResult;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum State {
     On,
     Off,
@@ -19,21 +18,21 @@ pub enum PulseType {
     Low,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ModuleType<'a> {
     FlipFlop(State),
     Conjunction(HashMap<&'a str, PulseType>),
     Broadcast,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Module<'a> {
     pub module_type: ModuleType<'a>,
     pub connections: Vec<&'a str>,
     pub identifier: &'a str,
 }
 
-fn module_type(input: &str) -> IResult<&str, ModuleType> {
+fn module_type(input: &str) -> IResult<&str, ModuleType<'_>> {
     alt((tag("%"), tag("&"), tag("")))(input).map(|(input, t)| match t {
         "%" => (input, ModuleType::FlipFlop(State::Off)),
         "&" => (input, ModuleType::Conjunction(HashMap::new())),
@@ -48,7 +47,7 @@ fn connections(input: &str) -> IResult<&str, Vec<&str>> {
     )(input)
 }
 
-fn module(input: &str) -> IResult<&str, (&str, Module)> {
+fn module(input: &str) -> IResult<&str, (&str, Module<'_>)> {
     tuple((module_type, take_till(|c| c == ' '), connections))(input).map(
         |(input, (module_type, identifier, connections))| {
             (
@@ -66,7 +65,6 @@ fn module(input: &str) -> IResult<&str, (&str, Module)> {
     )
 }
 
-pub fn modules(input: &str) -> IResult<&str, HashMap<&str, Module>> {
-    separated_list1(line_ending, module)(input)
-        .map(|(input, modules)| (input, modules.into_iter().collect()))
+pub fn modules(input: &str) -> IResult<&str, HashMap<&str, Module<'_>>> {
+    lines(module)(input).map(|(input, modules)| (input, modules.into_iter().collect()))
 }
@@ -1,6 +1,8 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
-use crate::parser::{self, Module, ModuleType, PulseType, State};
+use aoc_core::Result;
+
+use crate::parser::{Module, ModuleType, PulseType, State};
 
 #[derive(Debug)]
 struct Pulse<'a> {
@@ -9,14 +11,14 @@ struct Pulse<'a> {
     pulse_type: PulseType,
 }
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, mut modules) = parser::modules(input).ok()?;
+pub fn task(modules: &HashMap<&str, Module>) -> Result<String> {
+    let mut modules = modules.clone();
 
     // Pre-process all modules by adding initial memory to conjunctions, i.e.
     // memory for all connections to conjunctions will be initialized to low.
     let all_conjunctions: Vec<_> = modules
-        .iter()
-        .filter_map(|(_, m)| match m.module_type {
+        .values()
+        .filter_map(|m| match m.module_type {
             ModuleType::Conjunction(_) => Some(m.identifier),
             _ => None,
         })
@@ -24,8 +26,8 @@ pub fn task(input: &str) -> Option<String> {
 
     // All connections to conjunctions (from, to), where to is a conjunction
     let connections_to_conjunctions: Vec<(&str, &str)> = modules
-        .iter()
-        .flat_map(|(_, m)| {
+        .values()
+        .flat_map(|m| {
             m.connections
                 .iter()
                 .filter(|&&c| all_conjunctions.contains(&c))
@@ -148,26 +150,28 @@ pub fn task(input: &str) -> Option<String> {
         }
     }
 
-    Some((highs * lows).to_string())
+    Ok((highs * lows).to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day20;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day20::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "32000000");
     }
 
     #[test]
     fn test_task2() {
         let input = include_str!("../example2.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day20::solve_part1(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "11687500");
     }
 }
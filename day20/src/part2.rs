@@ -5,9 +5,12 @@
 //! to `rx`, and that `cn` is (backwards) connected to `ch`, `gh`, `sv`, and
 //! `th`.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
-use crate::parser::{self, Module, ModuleType, PulseType, State};
+use aoc_core::{Error, Result};
+use aoc_math::lcm_of;
+
+use crate::parser::{Module, ModuleType, PulseType, State};
 
 #[derive(Debug)]
 struct Pulse<'a> {
@@ -16,14 +19,14 @@ struct Pulse<'a> {
     pulse_type: PulseType,
 }
 
-pub fn task(input: &str) -> Option<String> {
-    let (_, mut modules) = parser::modules(input).ok()?;
+pub fn task(modules: &HashMap<&str, Module>) -> Result<String> {
+    let mut modules = modules.clone();
 
     // Pre-process all modules by adding initial memory to conjunctions, i.e.
     // memory for all connections to conjunctions will be initialized to low.
     let all_conjunctions: Vec<_> = modules
-        .iter()
-        .filter_map(|(_, m)| match m.module_type {
+        .values()
+        .filter_map(|m| match m.module_type {
             ModuleType::Conjunction(_) => Some(m.identifier),
             _ => None,
         })
@@ -31,8 +34,8 @@ pub fn task(input: &str) -> Option<String> {
 
     // All connections to conjunctions (from, to), where to is a conjunction
     let connections_to_conjunctions: Vec<(&str, &str)> = modules
-        .iter()
-        .flat_map(|(_, m)| {
+        .values()
+        .flat_map(|m| {
             m.connections
                 .iter()
                 .filter(|&&c| all_conjunctions.contains(&c))
@@ -57,7 +60,7 @@ pub fn task(input: &str) -> Option<String> {
     let mut cycle_lengths = Vec::new();
 
     // Press HQ button 1000 times, and count number of high and low pulses
-    for press in 1.. {
+    for press in 1u64.. {
         // Initial pulse
         pulses.push_back(Pulse {
             from: "HQ",
@@ -83,44 +86,15 @@ pub fn task(input: &str) -> Option<String> {
             // for `ch`, `gh`, `sv`, and `th`, we can calculate the least common
             // multiple of these cycle lengths, and that will be the number of
             // presses required to send a low pulse to `rx`.
-            match pulse {
-                Pulse {
-                    from: "ch",
-                    to: "cn",
-                    pulse_type: PulseType::High,
-                } => {
-                    if !cycle_lengths.iter().any(|(name, _)| *name == "ch") {
-                        cycle_lengths.push(("ch", press));
-                    }
+            if let Pulse {
+                from: name @ ("ch" | "gh" | "sv" | "th"),
+                to: "cn",
+                pulse_type: PulseType::High,
+            } = pulse
+            {
+                if !cycle_lengths.iter().any(|(n, _)| *n == name) {
+                    cycle_lengths.push((name, press));
                 }
-                Pulse {
-                    from: "gh",
-                    to: "cn",
-                    pulse_type: PulseType::High,
-                } => {
-                    if !cycle_lengths.iter().any(|(name, _)| *name == "gh") {
-                        cycle_lengths.push(("gh", press));
-                    }
-                }
-                Pulse {
-                    from: "sv",
-                    to: "cn",
-                    pulse_type: PulseType::High,
-                } => {
-                    if !cycle_lengths.iter().any(|(name, _)| *name == "sv") {
-                        cycle_lengths.push(("sv", press));
-                    }
-                }
-                Pulse {
-                    from: "th",
-                    to: "cn",
-                    pulse_type: PulseType::High,
-                } => {
-                    if !cycle_lengths.iter().any(|(name, _)| *name == "th") {
-                        cycle_lengths.push(("th", press));
-                    }
-                }
-                _ => {}
             }
 
             // Module to send pulse to
@@ -200,44 +174,26 @@ pub fn task(input: &str) -> Option<String> {
         }
     }
 
-    let cycle_lengths: Vec<usize> = cycle_lengths
-        .iter()
-        .map(|(_, cycle_length)| *cycle_length)
-        .collect();
-
     // Find least common multiple of all cycle lengths, i.e. press when all modules
     // send high pulses at the same time => rx receives low pulse
-    let lcm = lcm(&cycle_lengths);
+    let lcm = lcm_of(cycle_lengths.iter().map(|&(_, cycle_length)| cycle_length))
+        .map_err(|_| Error::invalid("button presses do not fit in 64 bits"))?;
 
-    Some(lcm.to_string())
-}
-
-fn lcm(numbers: &[usize]) -> usize {
-    if numbers.len() == 1 {
-        return numbers[0];
-    }
-    let a = numbers[0];
-    let b = lcm(&numbers[1..]);
-    a * b / gcd_of_two_numbers(a, b)
-}
-
-fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
-    if b == 0 {
-        return a;
-    }
-    gcd_of_two_numbers(b, a % b)
+    Ok(lcm.to_string())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day20;
 
     #[test]
     #[ignore = "No test case for part 2"]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day20::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "");
     }
 }
@@ -1,16 +1,20 @@
-pub fn task(_input: &str) -> Option<String> {
-    None
+use aoc_core::{Error, Result};
+
+pub fn task(_input: &str) -> Result<String> {
+    Err(Error::NoSolution)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::Solution;
+
+    use crate::Day01;
 
     #[test]
     fn test_task() {
         let input = include_str!("../example.txt");
-        let result = task(input);
-        assert!(result.is_some());
+        let result = Day01::solve_part2(input);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), "");
     }
 }